@@ -0,0 +1,291 @@
+//! Equinoxes and solstices, computed using the method from chapter 27 of
+//! Meeus's "Astronomical Algorithms".  The results are good to about a minute
+//! for years between 1000 and 3000, and degrade slowly outside that range.
+//!
+//! The sun's longitude is also available, using the lower-accuracy method of
+//! chapter 25, which is good to about 0.01° (or 15 minutes).
+
+use crate::Seasonlike;
+use chrono::{DateTime, Datelike, IsoWeek, Local, TimeZone, Utc};
+
+/// Coefficients of the polynomials giving the mean equinoxes and solstices
+/// for years -1000 to 1000 (table 27.A)
+const MEAN_BEFORE_1000: [[f64; 5]; 4] = [
+    [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+    [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+    [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074],
+    [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006],
+];
+
+/// As above, for years 1000 to 3000 (table 27.B)
+const MEAN_AFTER_1000: [[f64; 5]; 4] = [
+    [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+    [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+    [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+    [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+];
+
+/// Periodic terms (table 27.C)
+const PERIODIC: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// The March equinox, June solstice, September equinox, and December
+/// solstice of the given year
+pub fn equinoxes_and_solstices(year: i32) -> [DateTime<Utc>; 4] {
+    [0, 1, 2, 3].map(|i| {
+        let (coeffs, y) = if year < 1000 {
+            (MEAN_BEFORE_1000[i], f64::from(year) / 1000.0)
+        } else {
+            (MEAN_AFTER_1000[i], f64::from(year - 2000) / 1000.0)
+        };
+        let jde0 = coeffs.iter().rev().fold(0.0, |acc, c| acc * y + c);
+        let t = (jde0 - 2451545.0) / 36525.0;
+        let w = (35999.373 * t - 2.47).to_radians();
+        let dl = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+        let s: f64 = PERIODIC
+            .iter()
+            .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+            .sum();
+        let jde = jde0 + 0.00001 * s / dl;
+        from_julian_ephemeris_day(jde, year)
+    })
+}
+
+/// Convert a Julian Ephemeris Day (in Terrestrial Time) to UTC
+pub(crate) fn from_julian_ephemeris_day(jde: f64, year: i32) -> DateTime<Utc> {
+    let secs = (jde - 2440587.5) * 86400.0 - delta_t(year);
+    Utc.timestamp_opt(secs.round() as i64, 0).unwrap()
+}
+
+/// Convert from UTC to a Julian Ephemeris Day (in Terrestrial Time)
+fn to_julian_ephemeris_day(t: DateTime<Utc>) -> f64 {
+    (t.timestamp() as f64 + delta_t(t.year())) / 86400.0 + 2440587.5
+}
+
+/// The apparent longitude of the sun at the given instant, in degrees from 0
+/// to 360
+pub fn solar_longitude(t: DateTime<Utc>) -> f64 {
+    let t = (to_julian_ephemeris_day(t) - 2451545.0) / 36525.0;
+    let l0 = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let m = (357.52911 + 35999.05029 * t - 0.0001537 * t * t).to_radians();
+    let c = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin();
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    (l0 + c - 0.00569 - 0.00478 * omega.sin()).rem_euclid(360.0)
+}
+
+/// TT - UT, in seconds, using the polynomial fits of Espenak and Meeus
+fn delta_t(year: i32) -> f64 {
+    let y = f64::from(year) + 0.5;
+    let long_term = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+    match year {
+        1900..=1919 => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        1920..=1940 => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        1941..=1960 => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        1961..=1985 => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        1986..=2004 => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        2005..=2049 => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        2050..=2149 => long_term(y) - 0.5628 * (2150.0 - y),
+        _ => long_term(y),
+    }
+}
+
+/// Seasons bounded by the equinoxes and solstices.  Each season starts on the
+/// (local) week containing its equinox or solstice.
+///
+/// Astronomical winter runs from the December solstice to the March equinox,
+/// but a season can't cross the end of the year: seasons are ranges of week
+/// numbers within one year, and a season's year is the year of its weeks.  So
+/// winter is split in two, and its last week or two of December get a season
+/// of their own, Yule, to distinguish them from the Winter (or, in the
+/// southern hemisphere, Summer) which opens the year.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Autumn,
+    Yule,
+}
+
+impl Season {
+    pub const ALL: [Season; 5] = [
+        Season::Winter,
+        Season::Spring,
+        Season::Summer,
+        Season::Autumn,
+        Season::Yule,
+    ];
+}
+
+/// The numbers of the weeks containing the equinoxes and solstices of the
+/// given year
+fn boundaries(year: i32) -> [u8; 4] {
+    let system = crate::week::system();
+    equinoxes_and_solstices(year).map(|x| {
+        let week = system.week_of(x.with_timezone(&Local).date_naive());
+        system.number(week).1
+    })
+}
+
+impl Seasonlike for Season {
+    fn starting_week(self, year: i32) -> u8 {
+        match self {
+            Season::Winter => 1,
+            Season::Spring => boundaries(year)[0],
+            Season::Summer => boundaries(year)[1],
+            Season::Autumn => boundaries(year)[2],
+            Season::Yule => boundaries(year)[3],
+        }
+    }
+
+    fn ending_week(self, year: i32) -> u8 {
+        match self {
+            Season::Yule => crate::weeks_in_year(year),
+            _ => self.succ().starting_week(year) - 1,
+        }
+    }
+
+    fn from_week(week: IsoWeek) -> Season {
+        let (year, n) = crate::week::system().number(week);
+        let [spring, summer, autumn, yule] = boundaries(year);
+        match n {
+            x if x < spring => Season::Winter,
+            x if x < summer => Season::Spring,
+            x if x < autumn => Season::Summer,
+            x if x < yule => Season::Autumn,
+            _ => Season::Yule,
+        }
+    }
+
+    fn prev(self) -> Season {
+        use Season::*;
+        match self {
+            Winter => Yule,
+            Spring => Winter,
+            Summer => Spring,
+            Autumn => Summer,
+            Yule => Autumn,
+        }
+    }
+
+    fn succ(self) -> Season {
+        use Season::*;
+        match self {
+            Winter => Spring,
+            Spring => Summer,
+            Summer => Autumn,
+            Autumn => Yule,
+            Yule => Winter,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        use Season::*;
+        match self {
+            Winter => "Winter",
+            Spring => "Spring",
+            Summer => "Summer",
+            Autumn => "Autumn",
+            Yule => "Yule",
+        }
+    }
+
+    /// Yule keeps its name, since it's named after the solstice rather than
+    /// the weather
+    fn flip_hemisphere(self) -> Season {
+        use Season::*;
+        match self {
+            Winter => Summer,
+            Spring => Autumn,
+            Summer => Winter,
+            Autumn => Spring,
+            Yule => Yule,
+        }
+    }
+
+    /// The Japanese names
+    fn aliases(self) -> &'static [&'static str] {
+        use Season::*;
+        match self {
+            Winter => &["冬"],
+            Spring => &["春"],
+            Summer => &["夏"],
+            Autumn => &["秋"],
+            Yule => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equinoxes_and_solstices_2024() {
+        let published = [
+            (3, 20, 3, 6),
+            (6, 20, 20, 51),
+            (9, 22, 12, 44),
+            (12, 21, 9, 20),
+        ];
+        for (x, (month, day, hour, min)) in equinoxes_and_solstices(2024).into_iter().zip(published)
+        {
+            let expected = Utc
+                .with_ymd_and_hms(2024, month, day, hour, min, 0)
+                .unwrap();
+            let error = (x - expected).num_seconds().abs();
+            assert!(error <= 60, "{x} is {error}s from {expected}");
+        }
+    }
+}
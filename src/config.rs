@@ -0,0 +1,116 @@
+//! Settings read from `~/.config/wcal/config.toml`.
+//!
+//! ```toml
+//! hemisphere = "south"
+//! holidays = ["JP", "GB-ENG"]
+//! latitude = 51.48            # degrees north
+//! longitude = -0.01           # degrees east
+//! fiscal_year_start = 4       # April
+//! retail_pattern = "4-5-4"
+//! liturgical = true          # Lent and Advent follow the church calendar
+//! sprint_epoch = 2024-01-08
+//! sprint_length = 2           # weeks
+//! week_start = "sunday"
+//! week_numbering = "us"       # or "iso"
+//! weekend = ["friday", "saturday"]
+//! ```
+
+use crate::week::{Numbering, WeekSystem};
+use crate::{eight, fiscal, fiscal::RetailPattern, holiday::Region, week, Hemisphere};
+use anyhow::Context;
+use chrono::{NaiveDate, Weekday};
+use serde::{de::Error, Deserialize, Deserializer};
+use std::path::PathBuf;
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub hemisphere: Option<Hemisphere>,
+    /// The regions whose public holidays are shown, eg. "JP", "US", "DE",
+    /// "GB-ENG", "GB-SCT", or "GB-NIR"
+    pub holidays: Option<Vec<Region>>,
+    /// Where to calculate sunrise and sunset for, in degrees north
+    pub latitude: Option<f64>,
+    /// Where to calculate sunrise and sunset for, in degrees east
+    pub longitude: Option<f64>,
+    /// The month the fiscal year starts in, from 1 to 12
+    pub fiscal_year_start: Option<u32>,
+    pub retail_pattern: Option<RetailPattern>,
+    /// Whether Lent and Advent follow the church calendar
+    pub liturgical: Option<bool>,
+    /// The date that sprint 1 starts on
+    #[serde(deserialize_with = "date")]
+    pub sprint_epoch: Option<NaiveDate>,
+    /// The length of a sprint, in weeks
+    pub sprint_length: Option<u32>,
+    /// The first day of the week
+    #[serde(deserialize_with = "weekday")]
+    pub week_start: Option<Weekday>,
+    pub week_numbering: Option<Numbering>,
+    /// The days of the weekend
+    #[serde(deserialize_with = "weekdays")]
+    pub weekend: Option<Vec<Weekday>>,
+}
+
+fn date<'de, D: Deserializer<'de>>(d: D) -> Result<Option<NaiveDate>, D::Error> {
+    let x = toml::value::Datetime::deserialize(d)?;
+    let date = x.date.ok_or_else(|| D::Error::custom("expected a date"))?;
+    NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())
+        .map(Some)
+        .ok_or_else(|| D::Error::custom("invalid date"))
+}
+
+fn weekday<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Weekday>, D::Error> {
+    let x = String::deserialize(d)?;
+    x.parse()
+        .map(Some)
+        .map_err(|_| D::Error::custom(format!("unknown day: {x}")))
+}
+
+fn weekdays<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Vec<Weekday>>, D::Error> {
+    Vec::<String>::deserialize(d)?
+        .into_iter()
+        .map(|x| {
+            x.parse()
+                .map_err(|_| D::Error::custom(format!("unknown day: {x}")))
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("wcal/config.toml"))
+    }
+
+    /// Load the user's config.  If there's no config file, the defaults are
+    /// used.
+    pub fn load() -> anyhow::Result<Config> {
+        let Some(path) = Config::path() else {
+            return Ok(Config::default());
+        };
+        let txt = match std::fs::read_to_string(&path) {
+            Ok(txt) => txt,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(e).with_context(|| format!("Couldn't read {}", path.display())),
+        };
+        toml::from_str(&txt).with_context(|| format!("Invalid config in {}", path.display()))
+    }
+
+    /// Set up the week system and the calendars which have settings.  These
+    /// are global, so this should be called once, before any weeks or seasons
+    /// are used.
+    pub fn apply(&self) -> anyhow::Result<()> {
+        fiscal::configure(
+            self.fiscal_year_start.unwrap_or(1),
+            self.retail_pattern.unwrap_or_default(),
+        )?;
+        let default = WeekSystem::default();
+        week::configure(WeekSystem {
+            first_day: self.week_start.unwrap_or(default.first_day),
+            numbering: self.week_numbering.unwrap_or(default.numbering),
+            weekend: self.weekend.clone().unwrap_or(default.weekend),
+        })?;
+        eight::configure(self.liturgical.unwrap_or(false))
+    }
+}
@@ -0,0 +1,197 @@
+//! User-defined seasons, loaded from `~/.config/wcal/seasons`.
+//!
+//! Each line gives the name of a season followed by the number of the week it
+//! starts on.  Seasons must be listed in order, and the first must start in
+//! week 1.  Each season runs until the next one starts; the last one runs
+//! until the end of the year.  Names are matched ignoring case, so no two
+//! seasons can have names which differ only in case.
+//!
+//! ```text
+//! # name  starting-week
+//! Q1      1
+//! Q2      14
+//! Q3      27
+//! Q4      40
+//! ```
+//!
+//! Until a scheme is installed, the quarters in this example are used.
+
+use crate::Seasonlike;
+use anyhow::{anyhow, bail, ensure, Context};
+use chrono::IsoWeek;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+pub struct Scheme {
+    seasons: Vec<(String, u8)>,
+}
+
+impl Default for Scheme {
+    fn default() -> Scheme {
+        let seasons = [("Q1", 1), ("Q2", 14), ("Q3", 27), ("Q4", 40)];
+        Scheme {
+            seasons: seasons.map(|(x, w)| (x.to_string(), w)).to_vec(),
+        }
+    }
+}
+
+static SCHEME: OnceLock<Scheme> = OnceLock::new();
+
+fn scheme() -> &'static Scheme {
+    SCHEME.get_or_init(Scheme::default)
+}
+
+impl Scheme {
+    pub fn path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("wcal/seasons"))
+    }
+
+    /// Load the scheme from the user's config directory
+    pub fn load() -> anyhow::Result<Scheme> {
+        let path = Scheme::path().ok_or(anyhow!("Couldn't find the config directory"))?;
+        let txt = std::fs::read_to_string(&path)
+            .with_context(|| format!("Couldn't read {}", path.display()))?;
+        Scheme::parse(&txt).with_context(|| format!("Invalid season scheme in {}", path.display()))
+    }
+
+    pub fn parse(txt: &str) -> anyhow::Result<Scheme> {
+        let mut seasons: Vec<(String, u8)> = vec![];
+        for (i, line) in txt.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+            let lineno = i + 1;
+            let (name, week) = line.split_once(char::is_whitespace).ok_or(anyhow!(
+                "line {lineno}: expected a name and a starting week"
+            ))?;
+            let week: u8 = week
+                .trim()
+                .trim_start_matches(['w', 'W'])
+                .parse()
+                .with_context(|| format!("line {lineno}: bad week number"))?;
+            ensure!(
+                (1..=52).contains(&week),
+                "line {lineno}: week {week} is out of range"
+            );
+            if let Some((x, _)) = seasons
+                .iter()
+                .find(|(x, _)| x.to_lowercase() == name.to_lowercase())
+            {
+                if x == name {
+                    bail!("line {lineno}: {name} is defined twice");
+                }
+                bail!("line {lineno}: {name} is the same name as {x}, ignoring case");
+            }
+            match seasons.last() {
+                None if week != 1 => {
+                    bail!("line {lineno}: the first season must start in week 1, leaving a gap")
+                }
+                Some((prev, start)) if week <= *start => {
+                    bail!("line {lineno}: {name} starts in w{week}, overlapping {prev} which starts in w{start}")
+                }
+                _ => (),
+            }
+            seasons.push((name.to_string(), week));
+        }
+        ensure!(!seasons.is_empty(), "No seasons defined");
+        ensure!(seasons.len() <= 53, "Too many seasons defined");
+        Ok(Scheme { seasons })
+    }
+
+    /// Make this the scheme used by `custom::Season`, in place of the
+    /// default quarters.  Fails if a scheme is already in use.
+    pub fn install(self) -> anyhow::Result<()> {
+        SCHEME
+            .set(self)
+            .map_err(|_| anyhow!("A custom season scheme is already in use"))
+    }
+}
+
+/// A season from the installed `Scheme`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Season(u8);
+
+impl Seasonlike for Season {
+    fn starting_week(self, _year: i32) -> u8 {
+        scheme().seasons[self.0 as usize].1
+    }
+
+    fn ending_week(self, year: i32) -> u8 {
+        match scheme().seasons.get(self.0 as usize + 1) {
+            Some((_, next)) => next - 1,
+            None => crate::weeks_in_year(year),
+        }
+    }
+
+    fn from_week(week: IsoWeek) -> Season {
+        let (_, week) = crate::week::system().number(week);
+        let idx = scheme()
+            .seasons
+            .iter()
+            .rposition(|&(_, start)| start <= week)
+            .unwrap();
+        Season(idx as u8)
+    }
+
+    fn prev(self) -> Season {
+        let n = scheme().seasons.len() as u8;
+        Season((self.0 + n - 1) % n)
+    }
+
+    fn succ(self) -> Season {
+        let n = scheme().seasons.len() as u8;
+        Season((self.0 + 1) % n)
+    }
+
+    fn name(self) -> &'static str {
+        &scheme().seasons[self.0 as usize].0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(txt: &str) -> String {
+        match Scheme::parse(txt) {
+            Ok(_) => panic!("{txt:?} was accepted"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let scheme = Scheme::parse("# name week\nH1 w1\n\n// second half\nH2 W27\n").unwrap();
+        assert_eq!(
+            scheme.seasons,
+            [("H1".to_string(), 1), ("H2".to_string(), 27)]
+        );
+    }
+
+    #[test]
+    fn rejected() {
+        assert_eq!(
+            error("Q1 2\nQ2 14"),
+            "line 1: the first season must start in week 1, leaving a gap"
+        );
+        assert_eq!(
+            error("Q1 1\nQ2 14\nQ3 14"),
+            "line 3: Q3 starts in w14, overlapping Q2 which starts in w14"
+        );
+        assert_eq!(
+            error("Q1 1\nQ2 14\nQ3 10"),
+            "line 3: Q3 starts in w10, overlapping Q2 which starts in w14"
+        );
+        assert_eq!(error("Q1 1\nQ2 14\nQ1 27"), "line 3: Q1 is defined twice");
+        assert_eq!(
+            error("Q1 1\nq1 14"),
+            "line 2: q1 is the same name as Q1, ignoring case"
+        );
+        assert_eq!(error("Q1 0"), "line 1: week 0 is out of range");
+        assert_eq!(error("Q1 1\nQ2 53"), "line 2: week 53 is out of range");
+        assert_eq!(error("Q1 one"), "line 1: bad week number");
+        assert_eq!(error("Q1"), "line 1: expected a name and a starting week");
+        assert_eq!(error("# nothing\n"), "No seasons defined");
+    }
+}
@@ -0,0 +1,139 @@
+//! The date of Easter, and the moveable feasts which depend on it.
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// Easter Sunday in the Gregorian calendar, as observed by Western churches
+/// (the "anonymous Gregorian" algorithm, from chapter 8 of Meeus's
+/// "Astronomical Algorithms")
+pub fn western_easter(year: i32) -> NaiveDate {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+/// Easter Sunday as observed by Orthodox churches, which still use the Julian
+/// calendar to calculate it.  The date is given in the Gregorian calendar.
+pub fn orthodox_easter(year: i32) -> NaiveDate {
+    let a = year.rem_euclid(4);
+    let b = year.rem_euclid(7);
+    let c = year.rem_euclid(19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    // The Julian calendar falls behind by three days every four centuries
+    let julian_lag = year.div_euclid(100) - year.div_euclid(400) - 2;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+        + Duration::days(julian_lag.into())
+}
+
+/// The first Sunday of Advent: the fourth Sunday before Christmas
+pub fn advent_sunday(year: i32) -> NaiveDate {
+    let christmas_eve = NaiveDate::from_ymd_opt(year, 12, 24).unwrap();
+    let days_since_sunday = christmas_eve.weekday().num_days_from_sunday();
+    christmas_eve - Duration::days(i64::from(days_since_sunday)) - Duration::weeks(3)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Feast {
+    AshWednesday,
+    PalmSunday,
+    GoodFriday,
+    Easter,
+    OrthodoxEaster,
+    Ascension,
+    Pentecost,
+    AdventSunday,
+}
+
+impl Feast {
+    pub const ALL: [Feast; 8] = [
+        Feast::AshWednesday,
+        Feast::PalmSunday,
+        Feast::GoodFriday,
+        Feast::Easter,
+        Feast::OrthodoxEaster,
+        Feast::Ascension,
+        Feast::Pentecost,
+        Feast::AdventSunday,
+    ];
+
+    pub fn date(self, year: i32) -> NaiveDate {
+        let easter = western_easter(year);
+        match self {
+            Feast::AshWednesday => easter - Duration::days(46),
+            Feast::PalmSunday => easter - Duration::weeks(1),
+            Feast::GoodFriday => easter - Duration::days(2),
+            Feast::Easter => easter,
+            Feast::OrthodoxEaster => orthodox_easter(year),
+            Feast::Ascension => easter + Duration::days(39),
+            Feast::Pentecost => easter + Duration::weeks(7),
+            Feast::AdventSunday => advent_sunday(year),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Feast::AshWednesday => "Ash Wednesday",
+            Feast::PalmSunday => "Palm Sunday",
+            Feast::GoodFriday => "Good Friday",
+            Feast::Easter => "Easter",
+            Feast::OrthodoxEaster => "Orthodox Easter",
+            Feast::Ascension => "Ascension",
+            Feast::Pentecost => "Pentecost",
+            Feast::AdventSunday => "Advent Sunday",
+        }
+    }
+
+    /// The feasts falling on the given day.  Orthodox Easter is only
+    /// included when it differs from Western Easter.
+    pub fn on(date: NaiveDate) -> impl Iterator<Item = Feast> {
+        let year = date.year();
+        Feast::ALL.into_iter().filter(move |f| {
+            f.date(year) == date && !(*f == Feast::OrthodoxEaster && western_easter(year) == date)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn known_dates() {
+        assert_eq!(western_easter(2024), ymd(2024, 3, 31));
+        assert_eq!(orthodox_easter(2024), ymd(2024, 5, 5));
+        assert_eq!(western_easter(2025), ymd(2025, 4, 20));
+        assert_eq!(orthodox_easter(2025), ymd(2025, 4, 20));
+        assert_eq!(advent_sunday(2026), ymd(2026, 11, 29));
+        assert_eq!(advent_sunday(2027), ymd(2027, 11, 28));
+    }
+
+    #[test]
+    fn orthodox_easter_only_when_different() {
+        assert_eq!(
+            Feast::on(ymd(2025, 4, 20)).collect::<Vec<_>>(),
+            [Feast::Easter]
+        );
+        assert_eq!(
+            Feast::on(ymd(2024, 5, 5)).collect::<Vec<_>>(),
+            [Feast::OrthodoxEaster]
+        );
+    }
+}
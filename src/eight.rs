@@ -1,8 +1,28 @@
-use crate::Seasonlike;
-use anyhow::anyhow;
+use crate::{easter, week, Seasonlike};
+use anyhow::ensure;
+use chrono::{Duration, IsoWeek};
 use std::str::FromStr;
+use std::sync::OnceLock;
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+static LITURGICAL: OnceLock<bool> = OnceLock::new();
+
+/// Make Lent and Advent follow the church calendar.  Lent then runs from the
+/// week of Ash Wednesday to the week of Holy Saturday, and Advent from the
+/// first full week after Advent Sunday.  By default, Lent and Advent are
+/// fixed ranges of weeks, like the other seasons.
+pub fn configure(liturgical: bool) -> anyhow::Result<()> {
+    ensure!(
+        LITURGICAL.set(liturgical).is_ok(),
+        "The eight-season calendar is already configured"
+    );
+    Ok(())
+}
+
+fn liturgical() -> bool {
+    *LITURGICAL.get_or_init(|| false)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Season {
     Winter,
     Lent,
@@ -28,10 +48,23 @@ impl Season {
 }
 
 impl Seasonlike for Season {
-    fn starting_week(self) -> u8 {
+    fn starting_week(self, year: i32) -> u8 {
         use Season::*;
+        let system = week::system();
         match self {
             Winter => 1,
+            Lent if liturgical() => {
+                let ash_wednesday = easter::Feast::AshWednesday.date(year);
+                system.number(system.week_of(ash_wednesday)).1
+            }
+            Spring if liturgical() => {
+                let holy_saturday = easter::western_easter(year) - Duration::days(1);
+                system.number(system.week_of(holy_saturday)).1 + 1
+            }
+            Advent if liturgical() => {
+                let monday = easter::advent_sunday(year) + Duration::days(1);
+                system.number(system.week_of(monday)).1
+            }
             Lent => 8,
             Spring => 14,
             Tsuyu => 21,
@@ -42,33 +75,20 @@ impl Seasonlike for Season {
         }
     }
 
-    fn ending_week(self) -> u8 {
-        use Season::*;
+    fn ending_week(self, year: i32) -> u8 {
         match self {
-            Winter => 7,
-            Lent => 13,
-            Spring => 20,
-            Tsuyu => 26,
-            Summer => 33,
-            Obon => 39,
-            Autumn => 46,
-            Advent => 52,
+            Season::Advent => crate::weeks_in_year(year),
+            _ => self.succ().starting_week(year) - 1,
         }
     }
 
-    fn from_week(week: u32) -> Season {
-        match week % 52 {
-            0 => Season::Advent,
-            1..=7 => Season::Winter,
-            8..=13 => Season::Lent,
-            14..=20 => Season::Spring,
-            21..=26 => Season::Tsuyu,
-            27..=33 => Season::Summer,
-            34..=39 => Season::Obon,
-            40..=46 => Season::Autumn,
-            47..=51 => Season::Advent,
-            _ => unreachable!(),
-        }
+    fn from_week(week: IsoWeek) -> Season {
+        let (year, n) = week::system().number(week);
+        Season::ALL
+            .into_iter()
+            .rev()
+            .find(|s| s.starting_week(year) <= n)
+            .unwrap()
     }
 
     fn prev(self) -> Season {
@@ -98,22 +118,42 @@ impl Seasonlike for Season {
             Advent => Winter,
         }
     }
+
+    fn name(self) -> &'static str {
+        use Season::*;
+        match self {
+            Winter => "Winter",
+            Lent => "Lent",
+            Spring => "Spring",
+            Tsuyu => "Tsuyu",
+            Summer => "Summer",
+            Obon => "Obon",
+            Autumn => "Autumn",
+            Advent => "Advent",
+        }
+    }
+
+    /// The Japanese names
+    fn aliases(self) -> &'static [&'static str] {
+        use Season::*;
+        match self {
+            Winter => &["冬"],
+            Lent => &["四旬節"],
+            Spring => &["春"],
+            Tsuyu => &["梅雨"],
+            Summer => &["夏"],
+            Obon => &["お盆", "盆"],
+            Autumn => &["秋"],
+            Advent => &["待降節"],
+        }
+    }
 }
 
+/// See `Seasonlike::from_name`
 impl FromStr for Season {
     type Err = anyhow::Error;
     fn from_str(txt: &str) -> anyhow::Result<Season> {
-        match txt {
-            "Winter" => Ok(Season::Winter),
-            "Lent" => Ok(Season::Lent),
-            "Spring" => Ok(Season::Spring),
-            "Tsuyu" => Ok(Season::Tsuyu),
-            "Summer" => Ok(Season::Summer),
-            "Obon" => Ok(Season::Obon),
-            "Autumn" => Ok(Season::Autumn),
-            "Advent" => Ok(Season::Advent),
-            _ => Err(anyhow!("Unknown: {txt}")),
-        }
+        Ok(crate::spec::season_named(txt)?)
     }
 }
 
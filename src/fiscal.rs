@@ -0,0 +1,186 @@
+//! Fiscal quarters, and the periods of a retail 4-4-5 calendar.
+//!
+//! Both are set up from the config file (see `configure`).  Quarters start on
+//! the first week of their first month, by the same rule that the week system
+//! uses to decide where the year starts: with ISO weeks, the week containing
+//! the first Thursday.  A fiscal year is labelled with the year it starts in,
+//! so with a start in April, 2025-FQ4 is January to March 2026.  The retail
+//! calendar divides the year into twelve periods of four or five weeks; in
+//! years with 53 weeks, the extra week is added to the last period.
+
+use crate::{week, Seasonlike};
+use anyhow::ensure;
+use chrono::{Datelike, IsoWeek, NaiveDate};
+use serde::Deserialize;
+use std::sync::OnceLock;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize)]
+pub enum RetailPattern {
+    #[default]
+    #[serde(rename = "4-4-5")]
+    FourFourFive,
+    #[serde(rename = "4-5-4")]
+    FourFiveFour,
+    #[serde(rename = "5-4-4")]
+    FiveFourFour,
+}
+
+impl RetailPattern {
+    /// The lengths of the periods in each quarter
+    fn weeks(self) -> [u8; 3] {
+        match self {
+            RetailPattern::FourFourFive => [4, 4, 5],
+            RetailPattern::FourFiveFour => [4, 5, 4],
+            RetailPattern::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+struct Settings {
+    start_month: u32,
+    pattern: RetailPattern,
+}
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+fn settings() -> &'static Settings {
+    SETTINGS.get_or_init(|| Settings {
+        start_month: 1,
+        pattern: RetailPattern::default(),
+    })
+}
+
+/// Set the month the fiscal year starts in (which must be the first month of
+/// a calendar quarter) and the pattern of the retail calendar.  By default,
+/// the fiscal year starts in January and the retail calendar is 4-4-5.
+pub fn configure(start_month: u32, pattern: RetailPattern) -> anyhow::Result<()> {
+    ensure!(
+        matches!(start_month, 1 | 4 | 7 | 10),
+        "The fiscal year must start in January, April, July, or October"
+    );
+    ensure!(
+        SETTINGS
+            .set(Settings {
+                start_month,
+                pattern
+            })
+            .is_ok(),
+        "The fiscal calendar is already configured"
+    );
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Quarter {
+    FQ1,
+    FQ2,
+    FQ3,
+    FQ4,
+}
+
+impl Quarter {
+    pub const ALL: [Quarter; 4] = [Quarter::FQ1, Quarter::FQ2, Quarter::FQ3, Quarter::FQ4];
+
+    fn first_month(self) -> u32 {
+        (settings().start_month - 1 + 3 * self as u32) % 12 + 1
+    }
+}
+
+impl Seasonlike for Quarter {
+    fn year_offset(self) -> i32 {
+        i32::from(self.first_month() < settings().start_month)
+    }
+
+    fn starting_week(self, year: i32) -> u8 {
+        // The first week whose deciding day is in the month.  January's is
+        // always week 1.
+        let system = week::system();
+        let first = NaiveDate::from_ymd_opt(year, self.first_month(), 1).unwrap();
+        let mut week = system.week_of(first);
+        if system.deciding_date(week) < first {
+            week = system.succ(week);
+        }
+        system.number(week).1
+    }
+
+    fn ending_week(self, year: i32) -> u8 {
+        if self.succ().first_month() == 1 {
+            crate::weeks_in_year(year)
+        } else {
+            self.succ().starting_week(year) - 1
+        }
+    }
+
+    fn from_week(week: IsoWeek) -> Quarter {
+        let month = week::system().deciding_date(week).month();
+        Quarter::ALL[((month + 12 - settings().start_month) % 12 / 3) as usize]
+    }
+
+    fn prev(self) -> Quarter {
+        Quarter::ALL[(self as usize + 3) % 4]
+    }
+
+    fn succ(self) -> Quarter {
+        Quarter::ALL[(self as usize + 1) % 4]
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Quarter::FQ1 => "FQ1",
+            Quarter::FQ2 => "FQ2",
+            Quarter::FQ3 => "FQ3",
+            Quarter::FQ4 => "FQ4",
+        }
+    }
+}
+
+/// A period of the retail calendar, from 0 to 11
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Period(u8);
+
+impl Period {
+    fn len(self) -> u8 {
+        settings().pattern.weeks()[self.0 as usize % 3]
+    }
+}
+
+impl Seasonlike for Period {
+    fn starting_week(self, _year: i32) -> u8 {
+        1 + (0..self.0).map(|i| Period(i).len()).sum::<u8>()
+    }
+
+    fn ending_week(self, year: i32) -> u8 {
+        if self.0 == 11 {
+            crate::weeks_in_year(year)
+        } else {
+            self.starting_week(year) + self.len() - 1
+        }
+    }
+
+    fn from_week(week: IsoWeek) -> Period {
+        let (_, n) = week::system().number(week);
+        let mut end = 0;
+        for i in 0..12 {
+            end += Period(i).len();
+            if n <= end {
+                return Period(i);
+            }
+        }
+        Period(11)
+    }
+
+    fn prev(self) -> Period {
+        Period((self.0 + 11) % 12)
+    }
+
+    fn succ(self) -> Period {
+        Period((self.0 + 1) % 12)
+    }
+
+    fn name(self) -> &'static str {
+        const NAMES: [&str; 12] = [
+            "P1", "P2", "P3", "P4", "P5", "P6", "P7", "P8", "P9", "P10", "P11", "P12",
+        ];
+        NAMES[self.0 as usize]
+    }
+}
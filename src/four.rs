@@ -1,8 +1,9 @@
 pub use crate::{spec::*, *};
-use chrono::{Datelike, Local};
-use std::fmt::{self, Display};
+use chrono::IsoWeek;
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// The year divided into quarters of 13 weeks, named after the seasons.  In
+/// years with 53 weeks, Autumn is a week longer.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Season {
     Winter,
     Spring,
@@ -17,40 +18,50 @@ impl Season {
         Season::Summer,
         Season::Autumn,
     ];
+}
 
-    pub fn now() -> Season {
-        Season::from_week(Local::now().date_naive().iso_week().week())
+impl Seasonlike for Season {
+    fn starting_week(self, _year: i32) -> u8 {
+        use Season::*;
+        match self {
+            Winter => 1,
+            Spring => 14,
+            Summer => 27,
+            Autumn => 40,
+        }
     }
 
-    pub fn starting_week(self) -> u8 {
+    fn ending_week(self, year: i32) -> u8 {
         use Season::*;
         match self {
-            Winter => 0,
-            Spring => 7,
-            Summer => 20,
-            Autumn => 33,
+            Winter => 13,
+            Spring => 26,
+            Summer => 39,
+            Autumn => weeks_in_year(year),
         }
     }
 
-    pub fn from_week(week: u32) -> Season {
-        match week % 52 {
-            x if x < 7 => Season::Winter,
-            x if x < 20 => Season::Spring,
-            x if x < 33 => Season::Summer,
-            x if x < 46 => Season::Autumn,
+    fn from_week(week: IsoWeek) -> Season {
+        match week::system().number(week).1 {
+            1..=13 => Season::Winter,
+            14..=26 => Season::Spring,
+            27..=39 => Season::Summer,
+            40..=53 => Season::Autumn,
             _ => unreachable!(),
         }
     }
 
-    pub fn weeks(self) -> std::ops::Range<u8> {
-        if self == Season::Autumn {
-            self.starting_week()..52
-        } else {
-            self.starting_week()..self.succ().starting_week()
+    fn prev(self) -> Season {
+        use Season::*;
+        match self {
+            Spring => Winter,
+            Summer => Spring,
+            Autumn => Summer,
+            Winter => Autumn,
         }
     }
 
-    pub fn succ(self) -> Season {
+    fn succ(self) -> Season {
         use Season::*;
         match self {
             Winter => Spring,
@@ -59,27 +70,35 @@ impl Season {
             Autumn => Winter,
         }
     }
-}
 
-impl Display for Season {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "{:?} │ Mo Tu We Th Fr   Sa Su", self)?;
-        writeln!(f, "───────┼───────────────────────")?;
-        let today = Local::now().date_naive();
-        let starting_week = self.starting_week();
-        for week in self.weeks() {
-            write!(
-                f,
-                "{}",
-                PrettyWeek {
-                    year: today.year(),
-                    week,
-                    starting_week,
-                    today,
-                }
-            )?;
-            writeln!(f)?;
+    fn name(self) -> &'static str {
+        use Season::*;
+        match self {
+            Winter => "Winter",
+            Spring => "Spring",
+            Summer => "Summer",
+            Autumn => "Autumn",
+        }
+    }
+
+    /// The Japanese names
+    fn aliases(self) -> &'static [&'static str] {
+        use Season::*;
+        match self {
+            Winter => &["冬"],
+            Spring => &["春"],
+            Summer => &["夏"],
+            Autumn => &["秋"],
+        }
+    }
+
+    fn flip_hemisphere(self) -> Season {
+        use Season::*;
+        match self {
+            Winter => Summer,
+            Spring => Autumn,
+            Summer => Winter,
+            Autumn => Spring,
         }
-        Ok(())
     }
 }
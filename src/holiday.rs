@@ -0,0 +1,268 @@
+//! Public holidays, computed from rules rather than listed.  Only holidays
+//! observed throughout a country (or a nation of the UK) are included, and
+//! only under the current rules, so dates long in the past may be wrong.
+
+use crate::astro;
+use crate::easter::{self, Feast};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, serde::Deserialize)]
+pub enum Region {
+    #[serde(rename = "JP")]
+    Japan,
+    /// England and Wales, which share their bank holidays
+    #[serde(rename = "GB-ENG", alias = "GB-WLS")]
+    England,
+    #[serde(rename = "GB-SCT")]
+    Scotland,
+    #[serde(rename = "GB-NIR")]
+    NorthernIreland,
+    #[serde(rename = "US")]
+    UnitedStates,
+    #[serde(rename = "DE")]
+    Germany,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Holiday {
+    pub date: NaiveDate,
+    pub name: String,
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// The nth given weekday of the month
+fn nth(year: i32, month: u32, n: u8, day: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, day, n).unwrap()
+}
+
+/// The last given weekday of the month
+fn last(year: i32, month: u32, day: Weekday) -> NaiveDate {
+    let end = NaiveDate::from_ymd_opt(year, month + 1, 1)
+        .unwrap_or_else(|| ymd(year + 1, 1, 1))
+        .pred_opt()
+        .unwrap();
+    let days_since = (end.weekday().num_days_from_monday() + 7 - day.num_days_from_monday()) % 7;
+    end - Duration::days(i64::from(days_since))
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Holidays which fall on the given days are also observed on the next day
+/// which isn't already a holiday or one of those days
+fn substitute(holidays: &mut Vec<Holiday>, on: impl Fn(NaiveDate) -> bool) {
+    let mut substitutes = vec![];
+    for h in holidays.iter().filter(|h| on(h.date)) {
+        let date = h
+            .date
+            .iter_days()
+            .find(|d| {
+                !on(*d)
+                    && !holidays.iter().any(|h| h.date == *d)
+                    && !substitutes.iter().any(|h: &Holiday| h.date == *d)
+            })
+            .unwrap();
+        substitutes.push(Holiday {
+            date,
+            name: format!("{} (substitute)", h.name),
+        });
+    }
+    holidays.extend(substitutes);
+}
+
+/// The date of the given equinox in Japan
+fn equinox_in_japan(year: i32, index: usize) -> NaiveDate {
+    (astro::equinoxes_and_solstices(year)[index] + Duration::hours(9)).date_naive()
+}
+
+impl Region {
+    /// The holidays in the given year, in order
+    pub fn holidays(self, year: i32) -> Vec<Holiday> {
+        let h = |date, name: &str| Holiday {
+            date,
+            name: name.to_string(),
+        };
+        let easter = easter::western_easter(year);
+        // Feasts have the same names as in `--feasts`, so they're only listed
+        // once
+        let feast = |f: Feast| h(f.date(year), f.name());
+        let mut holidays = match self {
+            Region::Japan => {
+                let mut x = vec![
+                    h(ymd(year, 1, 1), "New Year's Day"),
+                    h(nth(year, 1, 2, Weekday::Mon), "Coming of Age Day"),
+                    h(ymd(year, 2, 11), "National Foundation Day"),
+                    h(equinox_in_japan(year, 0), "Vernal Equinox Day"),
+                    h(ymd(year, 4, 29), "Shōwa Day"),
+                    h(ymd(year, 5, 3), "Constitution Memorial Day"),
+                    h(ymd(year, 5, 4), "Greenery Day"),
+                    h(ymd(year, 5, 5), "Children's Day"),
+                    h(nth(year, 7, 3, Weekday::Mon), "Marine Day"),
+                    h(ymd(year, 8, 11), "Mountain Day"),
+                    h(nth(year, 9, 3, Weekday::Mon), "Respect for the Aged Day"),
+                    h(equinox_in_japan(year, 2), "Autumnal Equinox Day"),
+                    h(nth(year, 10, 2, Weekday::Mon), "Sports Day"),
+                    h(ymd(year, 11, 3), "Culture Day"),
+                    h(ymd(year, 11, 23), "Labour Thanksgiving Day"),
+                ];
+                if year >= 2020 {
+                    x.push(h(ymd(year, 2, 23), "Emperor's Birthday"));
+                } else if year <= 2018 {
+                    x.push(h(ymd(year, 12, 23), "Emperor's Birthday"));
+                }
+                // A day between two holidays is also a holiday
+                let days = x.iter().map(|h| h.date).collect::<Vec<_>>();
+                for d in &days {
+                    let next = *d + Duration::days(1);
+                    let after = *d + Duration::days(2);
+                    if days.contains(&after)
+                        && !days.contains(&next)
+                        && next.weekday() != Weekday::Sun
+                    {
+                        x.push(h(next, "Citizens' Holiday"));
+                    }
+                }
+                substitute(&mut x, |d| d.weekday() == Weekday::Sun);
+                x
+            }
+            Region::England | Region::Scotland | Region::NorthernIreland => {
+                let mut x = vec![
+                    h(ymd(year, 1, 1), "New Year's Day"),
+                    feast(Feast::GoodFriday),
+                    h(nth(year, 5, 1, Weekday::Mon), "Early May Bank Holiday"),
+                    h(last(year, 5, Weekday::Mon), "Spring Bank Holiday"),
+                    h(ymd(year, 12, 25), "Christmas Day"),
+                    h(ymd(year, 12, 26), "Boxing Day"),
+                ];
+                if self == Region::Scotland {
+                    x.push(h(ymd(year, 1, 2), "2nd January"));
+                    x.push(h(nth(year, 8, 1, Weekday::Mon), "Summer Bank Holiday"));
+                    x.push(h(ymd(year, 11, 30), "St Andrew's Day"));
+                } else {
+                    x.push(h(easter + Duration::days(1), "Easter Monday"));
+                    x.push(h(last(year, 8, Weekday::Mon), "Summer Bank Holiday"));
+                }
+                if self == Region::NorthernIreland {
+                    x.push(h(ymd(year, 3, 17), "St Patrick's Day"));
+                    x.push(h(ymd(year, 7, 12), "Battle of the Boyne"));
+                }
+                substitute(&mut x, is_weekend);
+                x
+            }
+            Region::UnitedStates => {
+                let mut x = vec![
+                    h(ymd(year, 1, 1), "New Year's Day"),
+                    h(nth(year, 1, 3, Weekday::Mon), "Martin Luther King Jr. Day"),
+                    h(nth(year, 2, 3, Weekday::Mon), "Washington's Birthday"),
+                    h(last(year, 5, Weekday::Mon), "Memorial Day"),
+                    h(ymd(year, 7, 4), "Independence Day"),
+                    h(nth(year, 9, 1, Weekday::Mon), "Labor Day"),
+                    h(nth(year, 10, 2, Weekday::Mon), "Columbus Day"),
+                    h(ymd(year, 11, 11), "Veterans Day"),
+                    h(nth(year, 11, 4, Weekday::Thu), "Thanksgiving Day"),
+                    h(ymd(year, 12, 25), "Christmas Day"),
+                ];
+                if year >= 2021 {
+                    x.push(h(ymd(year, 6, 19), "Juneteenth"));
+                }
+                // Holidays on a Saturday are observed on the Friday before,
+                // and those on a Sunday on the Monday after
+                let observed = x
+                    .iter()
+                    .filter_map(|x| {
+                        let date = match x.date.weekday() {
+                            Weekday::Sat => x.date - Duration::days(1),
+                            Weekday::Sun => x.date + Duration::days(1),
+                            _ => return None,
+                        };
+                        Some(h(date, &format!("{} (observed)", x.name)))
+                    })
+                    .collect::<Vec<_>>();
+                x.extend(observed);
+                x
+            }
+            Region::Germany => vec![
+                h(ymd(year, 1, 1), "New Year's Day"),
+                feast(Feast::GoodFriday),
+                h(easter + Duration::days(1), "Easter Monday"),
+                h(ymd(year, 5, 1), "Labour Day"),
+                feast(Feast::Ascension),
+                h(easter + Duration::days(50), "Whit Monday"),
+                h(ymd(year, 10, 3), "German Unity Day"),
+                h(ymd(year, 12, 25), "Christmas Day"),
+                h(ymd(year, 12, 26), "Second Day of Christmas"),
+            ],
+        };
+        holidays.sort_by_key(|h| h.date);
+        holidays
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The names of the holidays on the given date, from the rules for the
+    /// given year
+    fn on(region: Region, year: i32, date: NaiveDate) -> Vec<String> {
+        let holidays = region.holidays(year).into_iter();
+        holidays
+            .filter(|h| h.date == date)
+            .map(|h| h.name)
+            .collect()
+    }
+
+    #[test]
+    fn japan() {
+        let cases = [
+            // Silver Week
+            (ymd(2015, 9, 21), "Respect for the Aged Day"),
+            (ymd(2015, 9, 22), "Citizens' Holiday"),
+            (ymd(2015, 9, 23), "Autumnal Equinox Day"),
+            (ymd(2009, 9, 21), "Respect for the Aged Day"),
+            (ymd(2009, 9, 22), "Citizens' Holiday"),
+            (ymd(2009, 9, 23), "Autumnal Equinox Day"),
+            // Golden Week, with a holiday on a Sunday
+            (ymd(2025, 5, 4), "Greenery Day"),
+            (ymd(2025, 5, 6), "Greenery Day (substitute)"),
+            (ymd(2026, 5, 3), "Constitution Memorial Day"),
+            (ymd(2026, 5, 6), "Constitution Memorial Day (substitute)"),
+        ];
+        for (date, name) in cases {
+            assert_eq!(on(Region::Japan, date.year(), date), [name], "{date}");
+        }
+        assert_eq!(on(Region::Japan, 2025, ymd(2025, 9, 22)), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn gb_substitutes() {
+        // Christmas 2027 is on a Saturday
+        for region in [Region::England, Region::Scotland, Region::NorthernIreland] {
+            assert_eq!(
+                on(region, 2027, ymd(2027, 12, 27)),
+                ["Christmas Day (substitute)"]
+            );
+            assert_eq!(
+                on(region, 2027, ymd(2027, 12, 28)),
+                ["Boxing Day (substitute)"]
+            );
+        }
+    }
+
+    #[test]
+    fn us_observed() {
+        // 1st January 2028 is a Saturday, so it's observed the year before
+        assert_eq!(
+            on(Region::UnitedStates, 2028, ymd(2027, 12, 31)),
+            ["New Year's Day (observed)"]
+        );
+        assert_eq!(
+            on(Region::UnitedStates, 2026, ymd(2026, 7, 3)),
+            ["Independence Day (observed)"]
+        );
+    }
+}
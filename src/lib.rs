@@ -1,22 +1,123 @@
+pub mod astro;
+pub mod config;
+pub mod custom;
+pub mod easter;
 pub mod eight;
+pub mod fiscal;
 pub mod four;
+pub mod holiday;
+pub mod moon;
+pub mod seasonal;
+pub mod sekki;
 pub mod spec;
+pub mod sprint;
+pub mod sun;
+pub mod term;
+pub mod week;
 
-pub use crate::spec::*;
-use chrono::{Datelike, IsoWeek, Local, Month, NaiveDate, Weekday};
+pub use crate::{seasonal::Seasonal, spec::*};
+use chrono::{Datelike, IsoWeek, Local, Month, NaiveDate};
 use num_traits::FromPrimitive;
+use std::cmp::Ordering;
 use std::fmt::{self, Display};
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
 use yansi::{Color, Paint};
 
-pub trait Seasonlike: PartialEq + Copy {
-    fn starting_week(self) -> u8;
-    fn ending_week(self) -> u8;
-    fn from_week(week: u32) -> Self;
+/// A division of the year into seasons.
+///
+/// Seasons are ranges of weeks, as numbered by the configured week system
+/// (ISO 8601 by default).  In years with 53 weeks, week 53 belongs to the last
+/// season of the year, which is one week longer.
+pub trait Seasonlike: fmt::Debug + Eq + Hash + Copy {
+    /// The number of the first week of the season in the given year
+    fn starting_week(self, year: i32) -> u8;
+    /// How many years after the start of its calendar's year the season
+    /// falls in.  This is 1 for January to March in a fiscal year which
+    /// starts in April, and 0 otherwise.
+    fn year_offset(self) -> i32 {
+        0
+    }
+    /// The number of the last week of the season in the given year
+    fn ending_week(self, year: i32) -> u8;
+    fn from_week(week: IsoWeek) -> Self;
     fn prev(self) -> Self;
     fn succ(self) -> Self;
+    fn name(self) -> &'static str;
+    /// All the seasons, in order, starting with the one which opens the year
+    fn all() -> Vec<Self> {
+        let first = Self::from_week(week::system().week(2000, 1).unwrap());
+        std::iter::successors(Some(first), |s| Some(s.succ()).filter(|s| *s != first)).collect()
+    }
+    /// Other names for the season, such as its name in another language
+    fn aliases(self) -> &'static [&'static str] {
+        &[]
+    }
+    /// The seasons which the given name could refer to.  Names are matched
+    /// ignoring case, and can be abbreviated to any prefix; a name which
+    /// matches one season in full isn't ambiguous, even if it's a prefix of
+    /// another's.
+    fn matching(name: &str) -> Vec<Self> {
+        let name = name.to_lowercase();
+        let names = |s: &Self| {
+            std::iter::once(s.name())
+                .chain(s.aliases().iter().copied())
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+        };
+        let all = Self::all();
+        let exact = all.iter().filter(|s| names(s).contains(&name));
+        let exact = exact.copied().collect::<Vec<_>>();
+        if !exact.is_empty() {
+            return exact;
+        }
+        all.into_iter()
+            .filter(|s| names(s).iter().any(|x| x.starts_with(&name)))
+            .collect()
+    }
+    /// Look up a season by its name, or an unambiguous abbreviation of it
+    fn from_name(name: &str) -> Option<Self> {
+        match Self::matching(name)[..] {
+            [x] => Some(x),
+            _ => None,
+        }
+    }
+    /// The corresponding season in the other hemisphere.  Calendars whose
+    /// season names aren't tied to the weather return the season unchanged.
+    fn flip_hemisphere(self) -> Self {
+        self
+    }
+    /// The name of the season, as it's known in the given hemisphere
+    fn local_name(self, hemisphere: Hemisphere) -> &'static str {
+        match hemisphere {
+            Hemisphere::North => self.name(),
+            Hemisphere::South => self.flip_hemisphere().name(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Hemisphere {
+    #[default]
+    North,
+    South,
+}
+
+impl FromStr for Hemisphere {
+    type Err = anyhow::Error;
+    fn from_str(txt: &str) -> anyhow::Result<Hemisphere> {
+        match txt {
+            "north" => Ok(Hemisphere::North),
+            "south" => Ok(Hemisphere::South),
+            _ => Err(anyhow::anyhow!("Unknown hemisphere: {txt}")),
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// Ordered chronologically
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct YearSeason<T> {
     pub year: i32,
     pub season: T,
@@ -24,79 +125,161 @@ pub struct YearSeason<T> {
 
 impl<T: Seasonlike> YearSeason<T> {
     pub fn now() -> YearSeason<T> {
-        let today = Local::now().date_naive();
-        YearSeason {
-            year: today.year(),
-            season: T::from_week(today.iso_week().week()),
-        }
+        YearSeason::from_week(week::system().this_week())
+    }
+
+    /// The year which the season's weeks are numbered in
+    pub fn week_year(self) -> i32 {
+        self.year + self.season.year_offset()
     }
 
     pub fn weeks(self) -> std::ops::RangeInclusive<IsoWeek> {
-        let start =
-            NaiveDate::from_isoywd_opt(self.year, self.season.starting_week() as u32, Weekday::Mon)
-                .unwrap()
-                .iso_week();
-        let end =
-            NaiveDate::from_isoywd_opt(self.year, self.season.ending_week() as u32, Weekday::Mon)
-                .unwrap()
-                .iso_week();
-        start..=end
+        let system = week::system();
+        let year = self.week_year();
+        let week = |n: u8| system.week(year, u32::from(n)).unwrap();
+        week(self.season.starting_week(year))..=week(self.season.ending_week(year))
     }
 
     pub fn from_week(week: IsoWeek) -> YearSeason<T> {
+        let season = T::from_week(week);
         YearSeason {
-            year: week.year(),
-            season: T::from_week(week.week()),
+            year: week::system().number(week).0 - season.year_offset(),
+            season,
         }
     }
 
     pub fn prev(self) -> YearSeason<T> {
+        let year = self.week_year();
+        let prev = self.season.prev();
+        let prev_year = if prev.starting_week(year) >= self.season.starting_week(year) {
+            year - 1
+        } else {
+            year
+        };
         YearSeason {
-            year: if self.season == T::from_week(1) {
-                self.year - 1
-            } else {
-                self.year
-            },
-            season: self.season.prev(),
+            year: prev_year - prev.year_offset(),
+            season: prev,
         }
     }
 
     pub fn succ(self) -> YearSeason<T> {
+        let year = self.week_year();
+        let succ = self.season.succ();
+        let succ_year = if succ.starting_week(year) <= self.season.starting_week(year) {
+            year + 1
+        } else {
+            year
+        };
         YearSeason {
-            year: if self.season == T::from_week(52) {
-                self.year + 1
-            } else {
-                self.year
-            },
-            season: self.season.succ(),
+            year: succ_year - succ.year_offset(),
+            season: succ,
         }
     }
+
+    /// The season `n` seasons after this one, or `None` if it's too far away
+    /// for its weeks to be represented
+    pub fn checked_add(self, n: i32) -> Option<YearSeason<T>> {
+        // Whole years are skipped at once
+        let per_year = T::all().len() as i32;
+        let mut x = YearSeason {
+            year: self.year.checked_add(n / per_year)?,
+            season: self.season,
+        };
+        for _ in 0..(n % per_year).unsigned_abs() {
+            x = if n < 0 { x.prev() } else { x.succ() };
+        }
+        let first = NaiveDate::from_ymd_opt(x.year.checked_sub(1)?, 1, 1);
+        let last = NaiveDate::from_ymd_opt(x.year.checked_add(2)?, 12, 31);
+        (first.is_some() && last.is_some()).then_some(x)
+    }
+
+    /// The seasons from this one to `end`, inclusive
+    pub fn until(self, end: YearSeason<T>) -> impl Iterator<Item = YearSeason<T>> {
+        std::iter::successors(Some(self), |x| Some(x.succ())).take_while(move |x| *x <= end)
+    }
 }
 
-fn month_colour(month: u32) -> Color {
+impl<T: Seasonlike> Ord for YearSeason<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |x: &Self| (x.week_year(), x.season.starting_week(x.week_year()));
+        key(self).cmp(&key(other))
+    }
+}
+
+impl<T: Seasonlike> PartialOrd for YearSeason<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Move forward by the given number of seasons.  Panics if the result is out
+/// of range; see `YearSeason::checked_add`.
+impl<T: Seasonlike> Add<i32> for YearSeason<T> {
+    type Output = YearSeason<T>;
+    fn add(self, n: i32) -> YearSeason<T> {
+        self.checked_add(n).expect("Season out of range")
+    }
+}
+
+/// Move back by the given number of seasons
+impl<T: Seasonlike> Sub<i32> for YearSeason<T> {
+    type Output = YearSeason<T>;
+    fn sub(self, n: i32) -> YearSeason<T> {
+        self + -n
+    }
+}
+
+/// The number of weeks in the given year: either 52 or 53
+pub fn weeks_in_year(year: i32) -> u8 {
+    let system = week::system();
+    let weeks = system.year(year).expect("Year out of range");
+    system.number(*weeks.end()).1
+}
+
+fn month_colour(month: u32, hemisphere: Hemisphere) -> Color {
+    let month = match hemisphere {
+        Hemisphere::North => month,
+        Hemisphere::South => (month + 5) % 12 + 1,
+    };
     match month {
         12 | 1 | 2 => Color::Blue,
-        3 | 4 | 5 => Color::Green,
-        6 | 7 | 8 => Color::Yellow,
-        9 | 10 | 11 => Color::Red,
+        3..=5 => Color::Green,
+        6..=8 => Color::Yellow,
+        9..=11 => Color::Red,
         _ => panic!(),
     }
 }
 
 pub struct PrettyWeek {
-    pub year: i32,
-    pub week: u8,
-    pub starting_week: u8,
+    pub week: IsoWeek,
+    /// The number shown for the week.  By default, this is the week number in
+    /// the configured week system, but it can be made relative to a season.
+    /// Weeks which shouldn't be counted, such as breaks in a term, have no
+    /// number and are shown without colour.
+    pub weeknum: Option<u8>,
     pub today: NaiveDate,
+    pub hemisphere: Hemisphere,
+    /// Days to mark, such as the start of a solar term.  They're underlined,
+    /// and have a "·" in front of them.
+    pub marked: Vec<NaiveDate>,
+    /// Phases of the moon, shown by a glyph in front of the day, in place of
+    /// any other marker
+    pub moon: Vec<(NaiveDate, moon::Phase)>,
+    /// Public holidays, which are shown in their own colour with a "*" in
+    /// front of them
+    pub holidays: Vec<NaiveDate>,
 }
 
 impl PrettyWeek {
     pub fn new(week: IsoWeek) -> PrettyWeek {
         PrettyWeek {
-            year: week.year(),
-            week: week.week() as u8,
-            starting_week: 1,
+            week,
+            weeknum: Some(week::system().number(week).1),
             today: Local::now().date_naive(),
+            hemisphere: Hemisphere::North,
+            marked: vec![],
+            moon: vec![],
+            holidays: vec![],
         }
     }
 }
@@ -104,47 +287,57 @@ impl PrettyWeek {
 impl Display for PrettyWeek {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut new_month = None;
-        let this_week = self.today.iso_week();
-        let weeknum = self.week - self.starting_week + 1;
-        if self.week == this_week.week() as u8 && self.year == this_week.year() {
-            let x = format!(" ▶ w{weeknum:02}");
+        let system = week::system();
+        let this_week = system.week_of(self.today);
+        let label = match self.weeknum {
+            Some(n) => format!("w{n:02}"),
+            None => "───".to_string(),
+        };
+        if self.week == this_week {
+            let x = format!(" ▶ {label}");
             write!(f, "{}", Paint::new(x).bold())?;
         } else {
-            write!(f, "   w{weeknum:02}")?;
+            write!(f, "   {label}")?;
         }
         write!(f, " │")?;
-        for &day in &[
-            Weekday::Mon,
-            Weekday::Tue,
-            Weekday::Wed,
-            Weekday::Thu,
-            Weekday::Fri,
-            Weekday::Sat,
-            Weekday::Sun,
-        ] {
-            let date = if self.week == 0 {
-                NaiveDate::from_isoywd_opt(self.year - 1, 53, day).unwrap()
-            } else {
-                NaiveDate::from_isoywd_opt(self.year, self.week as u32, day).unwrap()
-            };
-            let color = month_colour(date.month());
+        let mut prev = None;
+        for date in system.dates(self.week) {
+            let day = date.weekday();
+            let color = month_colour(date.month(), self.hemisphere);
             let dimmed = matches!(date.month() % 3, 0 | 2);
             if date.day() == 1 {
                 new_month = Some(date.month());
             }
-            if day == Weekday::Sat {
+            if prev.is_some_and(|prev| system.is_weekend(prev) != system.is_weekend(day)) {
                 write!(f, "  ")?;
             }
-            if date == self.today {
-                write!(f, " {}", Paint::new(format!("{:2}", date.day())).bold())?;
+            prev = Some(day);
+            let x = format!("{:2}", date.day());
+            let mut x = if date == self.today {
+                Paint::new(x).bold()
+            } else if self.holidays.contains(&date) {
+                Color::Magenta.paint(x)
+            } else if self.weeknum.is_none() {
+                Paint::new(x).dimmed()
             } else if dimmed {
-                write!(f, " {}", color.paint(format!("{:2}", date.day())).dimmed())?;
+                color.paint(x).dimmed()
             } else {
-                write!(f, " {}", color.paint(format!("{:2}", date.day())))?;
+                color.paint(x)
+            };
+            if self.marked.contains(&date) {
+                x = x.underline();
             }
+            // Markers which survive without colour
+            let marker = match self.moon.iter().find(|(d, _)| *d == date) {
+                Some((_, phase)) => phase.glyph(),
+                None if self.holidays.contains(&date) => '*',
+                None if self.marked.contains(&date) => '·',
+                None => ' ',
+            };
+            write!(f, "{marker}{x}")?;
         }
         if let Some(m) = new_month {
-            let color = month_colour(m);
+            let color = month_colour(m, self.hemisphere);
             let dimmed = matches!(m % 3, 0 | 2);
             let x = &Month::from_u32(m).unwrap().name()[..3];
             if dimmed {
@@ -158,3 +351,70 @@ impl Display for PrettyWeek {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eight::Season::*;
+
+    fn ys<T>(year: i32, season: T) -> YearSeason<T> {
+        YearSeason { year, season }
+    }
+
+    #[test]
+    fn add_and_sub() {
+        assert_eq!(ys(2026, Winter) - 9, ys(2024, Advent));
+        assert_eq!(ys(2026, Winter) - 8, ys(2025, Winter));
+        assert_eq!(ys(2024, Advent) + 9, ys(2026, Winter));
+        assert_eq!(ys(2025, Obon) + 17, ys(2027, Autumn));
+        assert_eq!(ys(2025, Obon) + 0, ys(2025, Obon));
+        for n in -20..=20 {
+            assert_eq!(ys(2025, Obon) + n - n, ys(2025, Obon), "{n}");
+            assert_eq!(ys(2025, Obon) + n, ys(2025, Obon) - -n, "{n}");
+        }
+        assert_eq!(ys(2025, Obon).checked_add(i32::MAX), None);
+        assert_eq!(ys(2025, Obon).checked_add(i32::MIN), None);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut xs = vec![
+            ys(2025, Lent),
+            ys(2025, Winter),
+            ys(2024, Advent),
+            ys(2026, Winter),
+        ];
+        xs.sort();
+        assert_eq!(
+            xs,
+            [
+                ys(2024, Advent),
+                ys(2025, Winter),
+                ys(2025, Lent),
+                ys(2026, Winter)
+            ]
+        );
+    }
+
+    #[test]
+    fn until() {
+        let xs = ys(2024, Advent).until(ys(2025, Lent)).collect::<Vec<_>>();
+        assert_eq!(xs, [ys(2024, Advent), ys(2025, Winter), ys(2025, Lent)]);
+        assert_eq!(ys(2025, Lent).until(ys(2024, Advent)).count(), 0);
+        assert_eq!(ys(2025, Lent).until(ys(2025, Lent)).count(), 1);
+    }
+
+    #[test]
+    fn week_53() {
+        use four::Season::*;
+        // 2026 has 53 weeks and 2025 has 52
+        let len = |x: YearSeason<four::Season>| week::weeks_in_range(x.weeks()).count();
+        assert_eq!(len(ys(2026, Autumn)), 14);
+        assert_eq!(len(ys(2025, Autumn)), 13);
+        let week_53 = week::system().week(2026, 53).unwrap();
+        assert_eq!(YearSeason::from_week(week_53), ys(2026, Autumn));
+        assert_eq!(ys(2026, Autumn).succ(), ys(2027, Winter));
+        assert_eq!(ys(2027, Winter).prev(), ys(2026, Autumn));
+        assert_eq!(ys(2026, Autumn) + 1, ys(2027, Winter));
+    }
+}
@@ -1,21 +1,60 @@
 use bpaf::Bpaf;
 use chrono::*;
-use std::{collections::BTreeMap, io::Write};
+use std::{collections::BTreeMap, io::Write, str::FromStr};
 use tabwriter::TabWriter;
-use wcal::*;
+use wcal::spec::{ParseError, ParseErrorKind};
+use wcal::{config::Config, sprint::Sprints, term::Terms, *};
 use yansi::Paint;
 
 #[derive(Bpaf)]
 #[bpaf(options, fallback_to_usage)]
 struct Opts {
-    /// Show the specified week or range of weeks
-    spec: Option<WeeksSpec>,
+    /// The season calendar to use: "four", "eight", "astronomical", "fiscal"
+    /// (quarters), "retail" (4-4-5 periods), or "custom" (read from
+    /// ~/.config/wcal/seasons)
+    #[bpaf(argument("CALENDAR"), fallback(Calendar::Eight))]
+    calendar: Calendar,
+    /// "north" or "south".  Affects colours and the names of the four
+    /// seasons.  Defaults to the hemisphere in the config file
+    #[bpaf(argument("HEMISPHERE"))]
+    hemisphere: Option<Hemisphere>,
+    /// Make Lent and Advent in the eight-season calendar follow the church
+    /// calendar, rather than being fixed ranges of weeks
+    liturgical: bool,
+    #[bpaf(external)]
+    action: Action,
+}
+
+#[derive(Bpaf)]
+enum Action {
+    /// Convert Gregorian dates (eg. 2025-08-19) to seasonal dates, and
+    /// seasonal dates (eg. Obon-3-Tue) to Gregorian dates
+    #[bpaf(command)]
+    Convert {
+        /// The year of seasonal dates which don't specify one.  Defaults to
+        /// the current year
+        #[bpaf(argument("YEAR"))]
+        year: Option<i32>,
+        /// The dates to convert.  If none are given, they're read from
+        /// stdin, one per line.  Each date gets a line of output, which is
+        /// empty if it couldn't be converted, so that the output lines up
+        /// with the input
+        #[bpaf(positional("DATE"))]
+        dates: Vec<String>,
+    },
+    Show(#[bpaf(external(view))] View),
+}
+
+#[derive(Bpaf)]
+struct View {
     /// Show the current week
     #[bpaf(long, short)]
     week: bool,
-    /// Show the current year
-    #[bpaf(long, short)]
-    year: bool,
+    #[bpaf(external(year))]
+    year: Option<Option<i32>>,
+    /// Show a range of years, eg. 2025..2027
+    #[bpaf(argument("FROM..TO"))]
+    years: Option<Years>,
     /// Show the current month
     month: bool,
     /// Show the current season
@@ -25,8 +64,30 @@ struct Opts {
     continuous: bool,
     /// Break on months
     months: bool,
+    /// Break on sprints, as set up in the config file
+    sprints: bool,
+    /// Break on academic terms, as listed in ~/.config/wcal/terms
+    terms: bool,
     /// Number weeks relative to the season
     relative: bool,
+    /// Mark the days when each of the 24 solar terms (sekki) begins, and list
+    /// them next to the week
+    sekki: bool,
+    /// Mark the days when each of the 72 micro-seasons (kō) begins, and list
+    /// them next to the week
+    ko: bool,
+    /// Mark moveable feasts, such as Ash Wednesday, Easter, and Advent
+    /// Sunday, and list them next to the week
+    feasts: bool,
+    /// Show sunrise, sunset, and day length on the first day of each week,
+    /// and the change in day length since the week before.  Uses the
+    /// location in the config file
+    daylight: bool,
+    /// Mark new moons (●), first quarters (◐), full moons (○), and last
+    /// quarters (◑)
+    moon: bool,
+    /// Mark the phases of the moon, and list their times next to the week
+    moon_times: bool,
     /// Number of columns to print
     #[bpaf(fallback(3))]
     columns: usize,
@@ -34,37 +95,226 @@ struct Opts {
     no_color: bool,
     /// Print the date and exit
     date: bool,
+    /// The format of the date printed by --date.  Takes strftime-style
+    /// specifiers, plus %Q (season), %q (abbreviated season), %K (week of the
+    /// season), %L (weeks remaining in the season), %J (solar term), and %O
+    /// (micro-season).  With --sekki or --ko, the solar term or micro-season
+    /// is appended
+    #[bpaf(argument("FORMAT"), fallback("%Q-%K (%a)".to_string()))]
+    format: String,
+    // bpaf needs positionals to come last
+    #[bpaf(external(spec))]
+    spec: Option<String>,
 }
 
-fn parse_event(x: std::io::Result<String>) -> Option<(IsoWeek, String)> {
-    let x = x.unwrap();
-    if x.is_empty() || x.starts_with("#") || x.starts_with("//") {
-        None
-    } else {
-        let (week, event) = x.split_once(' ').unwrap();
-        Some((
-            wcal::spec::parse_one_week(week).unwrap(),
-            event.trim().to_string(),
-        ))
+/// The weeks to show, given positionally or (as in older versions) with
+/// `--spec`.  They're parsed later, since bpaf would reflow the error message.
+fn spec() -> impl bpaf::Parser<Option<String>> {
+    use bpaf::{construct, long, positional, Parser};
+    let named = long("spec").argument::<String>("SPEC").hide();
+    let positional = positional::<String>("SPEC").help(
+        "The weeks to show: a week (w05, 2025-w05), a day (2025-03-14), a month \
+         (March, 2025-03), a season (Obon, 2025-Obon), a year (2025), a period \
+         relative to today (today, +3w, next-season, prev-month, -1y), or a range \
+         between any of them (Tsuyu..Obon, 2025-03..2025-06, this-season..+2s, w10..)",
+    );
+    construct!([named, positional]).optional()
+}
+
+/// `--year`, with an optional year
+fn year() -> impl bpaf::Parser<Option<Option<i32>>> {
+    use bpaf::{construct, long, Parser};
+    let with_year = long("year")
+        .short('y')
+        .help("Show the given year, or the current one")
+        .argument::<i32>("YEAR")
+        .map(Some);
+    let without_year = long("year").short('y').req_flag(None).hide();
+    construct!([with_year, without_year]).optional()
+}
+
+/// An inclusive range of years
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Years {
+    from: i32,
+    to: i32,
+}
+
+impl FromStr for Years {
+    type Err = String;
+    fn from_str(txt: &str) -> Result<Years, String> {
+        let parse = |x: &str| {
+            x.parse::<i32>()
+                .map_err(|_| format!("Expected a year, but found \"{x}\""))
+        };
+        let (from, to) = match txt.split_once("..") {
+            Some((from, to)) => (parse(from)?, parse(to)?),
+            None => (parse(txt)?, parse(txt)?),
+        };
+        if from > to {
+            return Err(format!("{from} is after {to}"));
+        }
+        Ok(Years { from, to })
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Calendar {
+    Four,
+    Eight,
+    Astronomical,
+    Fiscal,
+    Retail,
+    Custom,
+}
+
+impl FromStr for Calendar {
+    type Err = String;
+    fn from_str(txt: &str) -> Result<Calendar, String> {
+        match txt {
+            "four" => Ok(Calendar::Four),
+            "eight" => Ok(Calendar::Eight),
+            "astronomical" => Ok(Calendar::Astronomical),
+            "fiscal" => Ok(Calendar::Fiscal),
+            "retail" => Ok(Calendar::Retail),
+            "custom" => Ok(Calendar::Custom),
+            _ => Err(format!(
+                "Unknown calendar: {txt} (expected \"four\", \"eight\", \"astronomical\", \"fiscal\", \"retail\" or \"custom\")"
+            )),
+        }
+    }
+}
+
+/// Parse a line of the events file: a week, or a day within it, followed by a
+/// description.  Blank lines and comments are skipped.
+fn parse_event(line: &str) -> Result<Option<(IsoWeek, String)>, ParseError> {
+    if line.is_empty() || line.starts_with("#") || line.starts_with("//") {
+        return Ok(None);
+    }
+    let Some((week, event)) = line.split_once(' ') else {
+        let span = line.len()..line.len();
+        return Err(ParseError::new(line, span, ParseErrorKind::NoDescription));
+    };
+    let week = wcal::spec::parse_one_week(week).map_err(|e| e.within(line, 0))?;
+    Ok(Some((week, event.trim().to_string())))
+}
+
 #[derive(PartialEq, Eq)]
 enum Grouping {
     None,
     Months,
     Seasons,
+    Sprints(Sprints),
+    Terms(Terms),
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = Config::load()?;
     let opts = opts().run();
+    if opts.liturgical {
+        config.liturgical = Some(true);
+    }
+    config.apply()?;
+    match opts.calendar {
+        Calendar::Four => run::<wcal::four::Season>(opts, config),
+        Calendar::Eight => run::<wcal::eight::Season>(opts, config),
+        Calendar::Astronomical => run::<wcal::astro::Season>(opts, config),
+        Calendar::Fiscal => run::<wcal::fiscal::Quarter>(opts, config),
+        Calendar::Retail => run::<wcal::fiscal::Period>(opts, config),
+        Calendar::Custom => {
+            wcal::custom::Scheme::load()?.install()?;
+            run::<wcal::custom::Season>(opts, config)
+        }
+    }
+}
 
-    if opts.date {
-        let season = wcal::YearSeason::<wcal::eight::Season>::now().season;
-        let week =
-            Local::now().date_naive().iso_week().week() - u32::from(season.starting_week()) + 1;
-        let day = Local::now().date_naive().weekday();
-        println!("{season:?}-{week} ({day})");
+fn run<S: Seasonlike>(opts: Opts, config: Config) -> Result<(), Box<dyn std::error::Error>> {
+    let hemisphere = opts.hemisphere.or(config.hemisphere).unwrap_or_default();
+    let system = wcal::week::system();
+    let season_name = |s: S| s.local_name(hemisphere);
+    // Season names in the user's hemisphere.  Flipping is its own inverse, so
+    // this also converts back again.
+    let localise = |mut d: Seasonal<S>| {
+        if hemisphere == Hemisphere::South {
+            d.season = d.season.flip_hemisphere();
+        }
+        d
+    };
+
+    let opts = match opts.action {
+        Action::Convert { year, dates } => {
+            let year = year.unwrap_or_else(|| system.number(system.this_week()).0);
+            let convert = |txt: &str| -> anyhow::Result<String> {
+                let txt = txt.trim();
+                Ok(match NaiveDate::parse_from_str(txt, "%Y-%m-%d") {
+                    Ok(date) => localise(Seasonal::<S>::from_date(date)).to_string(),
+                    Err(_) => {
+                        let seasonal = Seasonal::<S>::parse_with_default_year(txt, year)?;
+                        // The parser checks that the date exists
+                        let date = localise(seasonal).to_date().unwrap();
+                        date.format("%Y-%m-%d").to_string()
+                    }
+                })
+            };
+            let inputs: Box<dyn Iterator<Item = std::io::Result<String>>> = if dates.is_empty() {
+                use std::io::BufRead;
+                Box::new(std::io::stdin().lock().lines())
+            } else {
+                Box::new(dates.into_iter().map(Ok))
+            };
+            // Carry on past bad dates, reporting them on stderr
+            let mut failed = 0;
+            for input in inputs {
+                let input = input?;
+                if input.trim().is_empty() {
+                    println!();
+                    continue;
+                }
+                match convert(&input) {
+                    Ok(x) => println!("{x}"),
+                    Err(e) => {
+                        println!();
+                        eprintln!("{}: {e}", input.trim());
+                        failed += 1;
+                    }
+                }
+            }
+            if failed > 0 {
+                return Err(format!("{failed} of the dates couldn't be converted").into());
+            }
+            return Ok(());
+        }
+        Action::Show(view) => view,
+    };
+
+    let sprints = || -> anyhow::Result<Sprints> {
+        let epoch = config
+            .sprint_epoch
+            .ok_or_else(|| anyhow::anyhow!("Set sprint_epoch in the config file to use sprints"))?;
+        let length = config.sprint_length.unwrap_or(2);
+        anyhow::ensure!(length > 0, "sprint_length must be at least 1");
+        Ok(Sprints { epoch, length })
+    };
+
+    if opts.date && opts.sprints {
+        let today = Local::now().date_naive();
+        let sprint = sprints()?.sprint(system.week_of(today));
+        println!(
+            "Sprint {}, week {}, {}",
+            sprint.number,
+            sprint.week,
+            today.weekday()
+        );
+        return Ok(());
+    } else if opts.date {
+        let mut format = opts.format.clone();
+        if opts.sekki {
+            format.push_str(" · %J");
+        }
+        if opts.ko {
+            format.push_str(" · %O");
+        }
+        println!("{}", Seasonal::<S>::today().format(&format, hemisphere)?);
         return Ok(());
     }
 
@@ -72,81 +322,144 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         yansi::Paint::disable();
     }
 
-    let grouping = match (opts.continuous, opts.months) {
-        (true, true) => return Err("Can't pass both --months and --continuous".into()),
-        (true, false) => Grouping::None,
-        (false, true) => Grouping::Months,
-        (false, false) => Grouping::Seasons,
+    let grouping = match (opts.continuous, opts.months, opts.sprints, opts.terms) {
+        (false, false, false, false) => Grouping::Seasons,
+        (true, false, false, false) => Grouping::None,
+        (false, true, false, false) => Grouping::Months,
+        (false, false, true, false) => Grouping::Sprints(sprints()?),
+        (false, false, false, true) => Grouping::Terms(Terms::load()?),
+        _ => {
+            return Err(
+                "Only one of --continuous, --months, --sprints, and --terms can be given".into(),
+            )
+        }
     };
 
     use std::io::BufRead;
     let mut events = BTreeMap::<IsoWeek, Vec<String>>::default();
-    if let Ok(f) = std::fs::File::open(dirs::config_dir().unwrap().join("wcal/events")) {
-        for (week, ev) in std::io::BufReader::new(f).lines().flat_map(parse_event) {
-            events.entry(week).or_default().push(ev);
+    let path = dirs::config_dir().unwrap().join("wcal/events");
+    if let Ok(f) = std::fs::File::open(&path) {
+        for (n, line) in std::io::BufReader::new(f).lines().enumerate() {
+            let event = parse_event(&line?)
+                .map_err(|e| anyhow::anyhow!("{}:{}: {e}", path.display(), n + 1))?;
+            if let Some((week, ev)) = event {
+                events.entry(week).or_default().push(ev);
+            }
         }
     }
 
-    let range = if let Some(spec) = opts.spec {
-        spec.range()
-    } else if opts.year {
-        let year = Local::now().date_naive().year();
-        let (start, end) = if grouping == Grouping::None {
-            (
-                NaiveDate::from_ymd_opt(year, 1, 1).unwrap(),
-                NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
-            )
+    let years = match (opts.year, opts.years) {
+        (None, None) => None,
+        (Some(year), None) => {
+            let year = year.unwrap_or_else(|| Local::now().date_naive().year());
+            Some(Years {
+                from: year,
+                to: year,
+            })
+        }
+        (None, Some(years)) => Some(years),
+        (Some(_), Some(_)) => return Err("Only one of --year and --years can be given".into()),
+    };
+    if years.is_some() && opts.spec.is_some() {
+        return Err("--year and --years can't be given with a range of weeks".into());
+    }
+
+    let range = if let Some(spec) = &opts.spec {
+        let spec = WeeksSpec::parse::<S>(spec).map_err(anyhow::Error::new)?;
+        spec.range::<S>(hemisphere)?
+    } else if let Some(Years { from, to }) = years {
+        if grouping == Grouping::None {
+            system.week_of(NaiveDate::from_ymd_opt(from, 1, 1).ok_or("Year out of range")?)
+                ..=system.week_of(NaiveDate::from_ymd_opt(to, 12, 31).ok_or("Year out of range")?)
         } else {
-            (
-                NaiveDate::from_ymd_opt(year, 1, 4).unwrap(),
-                NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
-            )
-        };
-        start.iso_week()..=end.iso_week()
+            let from = system.year(from).ok_or("Year out of range")?;
+            let to = system.year(to).ok_or("Year out of range")?;
+            *from.start()..=*to.end()
+        }
     } else if opts.season {
-        wcal::YearSeason::<wcal::eight::Season>::now().weeks()
+        wcal::YearSeason::<S>::now().weeks()
     } else if opts.month {
-        let year = Local::now().date_naive().year();
-        let month = Local::now().date_naive().month();
-        let start = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
-        let end = NaiveDate::from_ymd_opt(year, month + 1, 1)
-            .unwrap()
-            .pred_opt()
-            .unwrap();
-        start.iso_week()..=end.iso_week()
+        DateObject::Relative(0, Unit::Month).weeks::<S>(hemisphere)?
     } else if opts.week {
-        let this_week = Local::now().date_naive().iso_week();
+        let this_week = system.this_week();
         this_week..=this_week
     } else if grouping == Grouping::None {
-        let today = Local::now().date_naive();
-        let start = today - Duration::weeks(3);
-        let end = today + Duration::weeks(9);
-        start.iso_week()..=end.iso_week()
+        let from = DateObject::Relative(-3, Unit::Week);
+        let to = DateObject::Relative(9, Unit::Week);
+        WeeksSpec::Range(from, to).range::<S>(hemisphere)?
+    } else if let Grouping::Sprints(sprints) = &grouping {
+        let this = sprints.sprint(system.this_week()).number;
+        (*sprints.weeks(this - 1).start())..=(*sprints.weeks(this + 1).end())
+    } else if let Grouping::Terms(terms) = &grouping {
+        let term = terms
+            .current_or_next(Local::now().date_naive())
+            .ok_or("There are no more terms")?;
+        let next = terms.next(term).unwrap_or(term);
+        (*term.weeks().start())..=(*next.weeks().end())
     } else {
-        let this = wcal::YearSeason::<wcal::eight::Season>::now();
-        (*this.weeks().start())..=(*this.succ().weeks().end())
+        let from = DateObject::Relative(0, Unit::Season);
+        let to = DateObject::Relative(1, Unit::Season);
+        WeeksSpec::Range(from, to).range::<S>(hemisphere)?
     };
 
+    let location = match (opts.daylight, config.latitude, config.longitude) {
+        (false, _, _) => None,
+        (true, Some(latitude), Some(longitude)) => Some(wcal::sun::Location {
+            latitude,
+            longitude,
+        }),
+        (true, _, _) => {
+            return Err("Set latitude and longitude in the config file to use --daylight".into())
+        }
+    };
+
+    let mut holidays = BTreeMap::<NaiveDate, Vec<String>>::default();
+    // Substitute holidays can be observed in the year before or after
+    for year in range.start().year() - 1..=range.end().year() + 1 {
+        for region in config.holidays.iter().flatten() {
+            for holiday in region.holidays(year) {
+                let names = holidays.entry(holiday.date).or_default();
+                if !names.contains(&holiday.name) {
+                    names.push(holiday.name);
+                }
+            }
+        }
+    }
+
     let mut groups = vec![];
     use std::fmt::Write;
     let mut buf = String::new();
+    let days = system.header();
+    let header = |buf: &mut String, name: &str| {
+        writeln!(buf, "{name:>6} │ {days}")?;
+        writeln!(buf, "───────┼{}", "─".repeat(days.len() + 1))
+    };
     if grouping == Grouping::None {
-        writeln!(buf, "       │ Mo Tu We Th Fr   Sa Su")?;
-        writeln!(buf, "───────┼───────────────────────")?;
+        header(&mut buf, "")?;
     }
     let mut season = None;
     let mut month = None;
-    for week in weeks_in_range(range) {
-        match grouping {
+    let mut sprint = None;
+    let mut term = None;
+    // There's a divider between years, and also before the first one if
+    // whole years were asked for
+    let mut year = None;
+    for week in wcal::week::weeks_in_range(range) {
+        let (week_year, _) = system.number(week);
+        let new_year = match year {
+            None => years.is_some(),
+            Some(y) => y != week_year,
+        };
+        year = Some(week_year);
+        match &grouping {
             Grouping::Seasons => {
-                let s = wcal::eight::Season::from_week(week.week());
+                let s = S::from_week(week);
                 if season != Some(s) {
-                    let sname = format!("{s:?}");
+                    let sname = season_name(s);
                     if season.is_some() {
                         groups.push(std::mem::take(&mut buf));
                     }
-                    writeln!(buf, "{sname:>6} │ Mo Tu We Th Fr   Sa Su")?;
-                    writeln!(buf, "───────┼───────────────────────")?;
+                    header(&mut buf, sname)?;
                     season = Some(s);
                 }
             }
@@ -158,22 +471,130 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     if month.is_some() {
                         groups.push(std::mem::take(&mut buf));
                     }
-                    writeln!(buf, "{mname:>6} │ Mo Tu We Th Fr   Sa Su")?;
-                    writeln!(buf, "───────┼───────────────────────")?;
+                    header(&mut buf, mname)?;
                     month = Some(m);
                 }
             }
+            Grouping::Sprints(sprints) => {
+                let s = sprints.sprint(week);
+                if sprint.map(|x: wcal::sprint::Sprint| x.number) != Some(s.number) {
+                    let sname = format!("S{}", s.number);
+                    if sprint.is_some() {
+                        groups.push(std::mem::take(&mut buf));
+                    }
+                    header(&mut buf, &sname)?;
+                }
+                sprint = Some(s);
+            }
+            Grouping::Terms(terms) => {
+                let t = terms.term(week).map(|t| t.name.as_str());
+                if term != Some(t) {
+                    // Weeks outside of any term are vacation
+                    let tname: String = t.unwrap_or("Vac").chars().take(6).collect();
+                    if term.is_some() {
+                        groups.push(std::mem::take(&mut buf));
+                    }
+                    header(&mut buf, &tname)?;
+                    term = Some(t);
+                }
+            }
+        }
+        if new_year {
+            writeln!(
+                buf,
+                "{:━>7}┿{}",
+                format!(" {week_year} "),
+                "━".repeat(days.len() + 1)
+            )?;
         }
         let mut pretty_week = PrettyWeek::new(week);
+        pretty_week.hemisphere = hemisphere;
         if opts.relative {
             if let Some(season) = season {
-                pretty_week.starting_week = season.starting_week();
+                let (year, n) = system.number(week);
+                pretty_week.weeknum = Some(n - season.starting_week(year) + 1);
+            } else if let Some(sprint) = sprint {
+                pretty_week.weeknum = Some(sprint.week as u8);
+            } else if let Grouping::Terms(terms) = &grouping {
+                if let Some(n) = terms.teaching_week(week) {
+                    pretty_week.weeknum = Some(n as u8);
+                }
+            }
+        }
+        if let Grouping::Terms(terms) = &grouping {
+            if terms.is_break(week) {
+                pretty_week.weeknum = None;
+            }
+        }
+        let mut labels = vec![];
+        if opts.moon || opts.moon_times {
+            let mut days = system.dates(week);
+            let start = days.next().unwrap();
+            let end = days.last().unwrap();
+            pretty_week.moon = wcal::moon::phases(start, end)
+                .into_iter()
+                .map(|(t, phase)| (t.date_naive(), phase))
+                .collect();
+        }
+        for day in system.dates(week) {
+            if let Some(names) = holidays.get(&day) {
+                pretty_week.holidays.push(day);
+                for name in names {
+                    labels.push(format!("{} {name}", day.day()));
+                }
+            }
+            let n = labels.len();
+            let sekki = opts.sekki.then(|| wcal::sekki::Sekki::starting_on(day));
+            let ko = opts.ko.then(|| wcal::sekki::Ko::starting_on(day));
+            for name in [
+                sekki.flatten().map(|x| x.name()),
+                ko.flatten().map(|x| x.name()),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                labels.push(format!("{} {name}", day.day()));
+            }
+            if opts.feasts {
+                for feast in wcal::easter::Feast::on(day) {
+                    // Skip feasts which are already listed as holidays
+                    let name = feast.name();
+                    if !holidays
+                        .get(&day)
+                        .is_some_and(|x| x.iter().any(|x| x == name))
+                    {
+                        labels.push(format!("{} {name}", day.day()));
+                    }
+                }
+            }
+            if labels.len() > n {
+                pretty_week.marked.push(day);
+            }
+            if opts.moon_times {
+                for (t, phase) in wcal::moon::phases(day, day) {
+                    labels.push(format!(
+                        "{} {} {}",
+                        day.day(),
+                        phase.glyph(),
+                        t.format("%H:%M")
+                    ));
+                }
             }
         }
         write!(buf, "{pretty_week}")?;
-        if let Some(evs) = events.get(&week) {
-            let evs = evs.join(" ▪ ");
-            if Local::now().date_naive().iso_week() == week {
+        if let Some(location) = location {
+            let day = system.dates(week).next().unwrap();
+            write!(buf, "{}", daylight(day, location))?;
+        }
+        let evs = events.get(&week).into_iter().flatten();
+        if !labels.is_empty() || events.contains_key(&week) {
+            let evs = labels
+                .iter()
+                .chain(evs)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" ▪ ");
+            if system.this_week() == week {
                 write!(buf, "  {}", evs)?;
             } else {
                 write!(buf, "  {}", Paint::new(evs).dimmed())?;
@@ -207,20 +628,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn week_to_month(week: IsoWeek) -> Month {
-    let m = NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Sun)
-        .unwrap()
-        .month();
-    Month::try_from(u8::try_from(m).unwrap()).unwrap()
+/// Sunrise, sunset, and day length on the given day, and the change in day
+/// length since a week before
+fn daylight(day: NaiveDate, location: wcal::sun::Location) -> String {
+    use wcal::sun::Daylight;
+    let this = wcal::sun::daylight(day, location);
+    let last = wcal::sun::daylight(day - Duration::weeks(1), location);
+    let time = |t: DateTime<Utc>| t.with_timezone(&Local).format("%H:%M").to_string();
+    let (sunrise, sunset) = match this {
+        Daylight::Sun { sunrise, sunset } => (time(sunrise), time(sunset)),
+        Daylight::PolarDay | Daylight::PolarNight => ("--:--".into(), "--:--".into()),
+    };
+    let length = this.length().num_minutes();
+    let change = length - last.length().num_minutes();
+    format!(
+        " ↑{sunrise} ↓{sunset} {:2}h{:02}m {:>5}",
+        length / 60,
+        length % 60,
+        format!("{change:+}m"),
+    )
 }
 
-fn weeks_in_range(range: std::ops::RangeInclusive<IsoWeek>) -> impl Iterator<Item = IsoWeek> {
-    let start =
-        NaiveDate::from_isoywd_opt(range.start().year(), range.start().week(), Weekday::Wed)
-            .unwrap();
-    let end = *range.end();
-    start
-        .iter_weeks()
-        .map(|x| x.iso_week())
-        .take_while(move |x| *x <= end)
+fn week_to_month(week: IsoWeek) -> Month {
+    let m = wcal::week::system().dates(week).last().unwrap().month();
+    Month::try_from(u8::try_from(m).unwrap()).unwrap()
 }
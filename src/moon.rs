@@ -0,0 +1,160 @@
+//! Phases of the moon, computed using the method from chapter 49 of Meeus's
+//! "Astronomical Algorithms".  The results are good to about a minute.
+
+use crate::astro::from_julian_ephemeris_day;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Phase {
+    New,
+    FirstQuarter,
+    Full,
+    LastQuarter,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [
+        Phase::New,
+        Phase::FirstQuarter,
+        Phase::Full,
+        Phase::LastQuarter,
+    ];
+
+    pub fn glyph(self) -> char {
+        match self {
+            Phase::New => '●',
+            Phase::FirstQuarter => '◐',
+            Phase::Full => '○',
+            Phase::LastQuarter => '◑',
+        }
+    }
+}
+
+/// Periodic terms for new moons, in the form (coefficient, e, m, m', f), where
+/// each term is coefficient × E^e × sin(m·M + m'·M' + f·F).  The terms for full
+/// moons are the same after the first fourteen.
+#[rustfmt::skip]
+const NEW: [(f64, i32, f64, f64, f64); 24] = [
+    (-0.40720, 0, 0.0, 1.0, 0.0), (0.17241, 1, 1.0, 0.0, 0.0),
+    (0.01608, 0, 0.0, 2.0, 0.0), (0.01039, 0, 0.0, 0.0, 2.0),
+    (0.00739, 1, -1.0, 1.0, 0.0), (-0.00514, 1, 1.0, 1.0, 0.0),
+    (0.00208, 2, 2.0, 0.0, 0.0), (-0.00111, 0, 0.0, 1.0, -2.0),
+    (-0.00057, 0, 0.0, 1.0, 2.0), (0.00056, 1, 1.0, 2.0, 0.0),
+    (-0.00042, 0, 0.0, 3.0, 0.0), (0.00042, 1, 1.0, 0.0, 2.0),
+    (0.00038, 1, 1.0, 0.0, -2.0), (-0.00024, 1, -1.0, 2.0, 0.0),
+    (-0.00007, 0, 2.0, 1.0, 0.0), (0.00004, 0, 0.0, 2.0, -2.0),
+    (0.00004, 0, 3.0, 0.0, 0.0), (0.00003, 0, 1.0, 1.0, -2.0),
+    (0.00003, 0, 0.0, 2.0, 2.0), (-0.00003, 0, 1.0, 1.0, 2.0),
+    (0.00003, 0, -1.0, 1.0, 2.0), (-0.00002, 0, -1.0, 1.0, -2.0),
+    (-0.00002, 0, 1.0, 3.0, 0.0), (0.00002, 0, 0.0, 4.0, 0.0),
+];
+
+/// The first fourteen periodic terms for full moons; the rest are as for new
+/// moons
+#[rustfmt::skip]
+const FULL: [(f64, i32, f64, f64, f64); 14] = [
+    (-0.40614, 0, 0.0, 1.0, 0.0), (0.17302, 1, 1.0, 0.0, 0.0),
+    (0.01614, 0, 0.0, 2.0, 0.0), (0.01043, 0, 0.0, 0.0, 2.0),
+    (0.00734, 1, -1.0, 1.0, 0.0), (-0.00515, 1, 1.0, 1.0, 0.0),
+    (0.00209, 2, 2.0, 0.0, 0.0), (-0.00111, 0, 0.0, 1.0, -2.0),
+    (-0.00057, 0, 0.0, 1.0, 2.0), (0.00056, 1, 1.0, 2.0, 0.0),
+    (-0.00042, 0, 0.0, 3.0, 0.0), (0.00042, 1, 1.0, 0.0, 2.0),
+    (0.00038, 1, 1.0, 0.0, -2.0), (-0.00024, 1, -1.0, 2.0, 0.0),
+];
+
+/// Periodic terms for first and last quarters
+#[rustfmt::skip]
+const QUARTER: [(f64, i32, f64, f64, f64); 24] = [
+    (-0.62801, 0, 0.0, 1.0, 0.0), (0.17172, 1, 1.0, 0.0, 0.0),
+    (-0.01183, 1, 1.0, 1.0, 0.0), (0.00862, 0, 0.0, 2.0, 0.0),
+    (0.00804, 0, 0.0, 0.0, 2.0), (0.00454, 1, -1.0, 1.0, 0.0),
+    (0.00204, 2, 2.0, 0.0, 0.0), (-0.00180, 0, 0.0, 1.0, -2.0),
+    (-0.00070, 0, 0.0, 1.0, 2.0), (-0.00040, 0, 0.0, 3.0, 0.0),
+    (-0.00034, 1, -1.0, 2.0, 0.0), (0.00032, 1, 1.0, 0.0, 2.0),
+    (0.00032, 1, 1.0, 0.0, -2.0), (-0.00028, 2, 2.0, 1.0, 0.0),
+    (0.00027, 1, 1.0, 2.0, 0.0), (-0.00005, 0, -1.0, 1.0, -2.0),
+    (0.00004, 0, 0.0, 2.0, 2.0), (-0.00004, 0, 1.0, 1.0, 2.0),
+    (0.00004, 0, -2.0, 1.0, 0.0), (0.00003, 0, 1.0, 1.0, -2.0),
+    (0.00003, 0, 3.0, 0.0, 0.0), (0.00002, 0, 0.0, 2.0, -2.0),
+    (0.00002, 0, -1.0, 1.0, 2.0), (-0.00002, 0, 1.0, 3.0, 0.0),
+];
+
+/// Coefficients of the planetary arguments A1 to A14, as (coefficient,
+/// constant, rate per lunation)
+#[rustfmt::skip]
+const PLANETARY: [(f64, f64, f64); 14] = [
+    (0.000325, 299.77, 0.107408), (0.000165, 251.88, 0.016321),
+    (0.000164, 251.83, 26.651886), (0.000126, 349.42, 36.412478),
+    (0.000110, 84.66, 18.206239), (0.000062, 141.74, 53.303771),
+    (0.000060, 207.14, 2.453732), (0.000056, 154.84, 7.306860),
+    (0.000047, 34.52, 27.261239), (0.000042, 207.19, 0.121824),
+    (0.000040, 291.34, 1.844379), (0.000037, 161.72, 24.198154),
+    (0.000035, 239.56, 25.513099), (0.000023, 331.55, 3.592518),
+];
+
+/// The instant of the phase of the moon with the given (fractional) lunation
+/// number.  Lunation 0 is the new moon of 6th January 2000; the fractional part
+/// gives the phase.
+fn phase_time(k: f64, phase: Phase) -> DateTime<Local> {
+    let t = k / 1236.85;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let m = (2.5534 + 29.1053567 * k - 0.0000014 * t * t - 0.00000011 * t * t * t).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t * t + 0.00001238 * t * t * t
+        - 0.000000058 * t * t * t * t)
+        .to_radians();
+    let f = (160.7108 + 390.67050284 * k - 0.0016118 * t * t - 0.00000227 * t * t * t
+        + 0.000000011 * t * t * t * t)
+        .to_radians();
+    let omega =
+        (124.7746 - 1.56375588 * k + 0.0020672 * t * t + 0.00000215 * t * t * t).to_radians();
+
+    let term = |&(a, ep, cm, cmp, cf): &(f64, i32, f64, f64, f64)| {
+        a * e.powi(ep) * (cm * m + cmp * mp + cf * f).sin()
+    };
+    let mut correction = match phase {
+        Phase::New => NEW.iter().map(term).sum::<f64>(),
+        Phase::Full => FULL.iter().chain(&NEW[14..]).map(term).sum::<f64>(),
+        Phase::FirstQuarter | Phase::LastQuarter => {
+            let w = 0.00306 - 0.00038 * e * m.cos() + 0.00026 * mp.cos() - 0.00002 * (mp - m).cos()
+                + 0.00002 * (mp + m).cos()
+                + 0.00002 * (2.0 * f).cos();
+            let w = if phase == Phase::FirstQuarter { w } else { -w };
+            QUARTER.iter().map(term).sum::<f64>() + w
+        }
+    };
+    correction -= 0.00017 * omega.sin();
+    correction += PLANETARY
+        .iter()
+        .enumerate()
+        .map(|(i, (a, c, r))| {
+            let x = c + r * k - if i == 0 { 0.009173 * t * t } else { 0.0 };
+            a * x.to_radians().sin()
+        })
+        .sum::<f64>();
+
+    let jde = 2451550.09766 + 29.530588861 * k + 0.00015437 * t * t - 0.00000015 * t * t * t
+        + 0.00000000073 * t * t * t * t
+        + correction;
+    let year = 2000 + (k / 12.3685) as i32;
+    from_julian_ephemeris_day(jde, year).with_timezone(&Local)
+}
+
+/// The principal phases of the moon between the given dates (inclusive), in
+/// order
+pub fn phases(start: NaiveDate, end: NaiveDate) -> Vec<(DateTime<Local>, Phase)> {
+    let years = f64::from(start.year() - 2000) + f64::from(start.ordinal0()) / 365.25;
+    // Start a lunation early, to be sure not to miss any
+    let first = (years * 12.3685).floor() as i64 - 1;
+    let mut ret = vec![];
+    for k in first.. {
+        for (i, phase) in Phase::ALL.into_iter().enumerate() {
+            let t = phase_time(k as f64 + i as f64 / 4.0, phase);
+            if t.date_naive() > end {
+                return ret;
+            } else if t.date_naive() >= start {
+                ret.push((t, phase));
+            }
+        }
+    }
+    ret
+}
@@ -0,0 +1,382 @@
+//! Dates written as a season, a week of that season, and a day of the week;
+//! eg. "Obon-3 (Tue)".
+
+use crate::sekki::{Ko, Sekki};
+use crate::{spec::season_named, week, Hemisphere, Seasonlike, YearSeason};
+use anyhow::{anyhow, ensure};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveTime, TimeZone, Weekday};
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Write};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// Ordered chronologically
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Seasonal<T> {
+    /// The year of the calendar, which is the year its weeks are numbered in
+    /// except in fiscal years which don't start in January
+    pub year: i32,
+    pub season: T,
+    /// The week of the season, starting from 1
+    pub week: u8,
+    pub day: Weekday,
+}
+
+impl<T: Seasonlike> Seasonal<T> {
+    pub fn today() -> Seasonal<T> {
+        Seasonal::from_date(Local::now().date_naive())
+    }
+
+    pub fn from_date(date: NaiveDate) -> Seasonal<T> {
+        let system = week::system();
+        let week = system.week_of(date);
+        let (week_year, n) = system.number(week);
+        let YearSeason { year, season } = YearSeason::<T>::from_week(week);
+        Seasonal {
+            year,
+            season,
+            week: n - season.starting_week(week_year) + 1,
+            day: date.weekday(),
+        }
+    }
+
+    /// Returns `None` if the season doesn't have the given week
+    pub fn to_date(self) -> Option<NaiveDate> {
+        let year = self.year_season().week_year();
+        let start = self.season.starting_week(year);
+        let length = self.season.ending_week(year) - start + 1;
+        if self.week == 0 || self.week > length {
+            return None;
+        }
+        let week = week::system().week(year, u32::from(start + self.week - 1))?;
+        Some(week::system().date(week, self.day))
+    }
+
+    pub fn year_season(self) -> YearSeason<T> {
+        YearSeason {
+            year: self.year,
+            season: self.season,
+        }
+    }
+
+    /// The number of whole weeks from this date to `other`
+    pub fn weeks_until(self, other: Seasonal<T>) -> i64 {
+        (other - self).num_weeks()
+    }
+
+    /// The days from this one to `end`, inclusive
+    pub fn until(self, end: Seasonal<T>) -> impl Iterator<Item = Seasonal<T>> {
+        let start = self.date();
+        let end = end.date();
+        start
+            .iter_days()
+            .take_while(move |x| *x <= end)
+            .map(Seasonal::from_date)
+    }
+
+    /// Format the date using a strftime-style format string.  As well as the
+    /// specifiers supported by chrono, the following are available:
+    ///
+    /// * `%Q`: the name of the season, eg. "Obon"
+    /// * `%q`: the abbreviated name of the season, eg. "Obo"
+    /// * `%K`: the week of the season, starting from 1
+    /// * `%L`: the number of weeks remaining in the season, not counting this
+    ///   one
+    /// * `%J`: the current solar term (sekki), eg. "処暑"
+    /// * `%O`: the current micro-season (kō), eg. "綿柎開"
+    ///
+    /// The ISO week number is available as `%V`.  Time specifiers give the
+    /// current time if the date is today, and midnight otherwise.
+    pub fn format(self, fmt: &str, hemisphere: Hemisphere) -> anyhow::Result<String> {
+        let name = self.season.local_name(hemisphere);
+        let year = self.year_season().week_year();
+        let remaining =
+            self.season.ending_week(year) - self.season.starting_week(year) + 1 - self.week;
+        let mut chrono_fmt = String::new();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                chrono_fmt.push(c);
+                continue;
+            }
+            let escaped = |x: &str| x.replace('%', "%%");
+            match chars.next() {
+                Some('Q') => chrono_fmt.push_str(&escaped(name)),
+                Some('q') => {
+                    chrono_fmt.push_str(&escaped(&name.chars().take(3).collect::<String>()))
+                }
+                Some('K') => chrono_fmt.push_str(&self.week.to_string()),
+                Some('L') => chrono_fmt.push_str(&remaining.to_string()),
+                Some('J') => chrono_fmt.push_str(Sekki::on(self.date()).name()),
+                Some('O') => chrono_fmt.push_str(Ko::on(self.date()).name()),
+                Some(c) => {
+                    chrono_fmt.push('%');
+                    chrono_fmt.push(c);
+                }
+                None => chrono_fmt.push('%'),
+            }
+        }
+        let items = StrftimeItems::new(&chrono_fmt).collect::<Vec<_>>();
+        ensure!(
+            !items.contains(&Item::Error),
+            "Invalid format string: {fmt}"
+        );
+        // Time specifiers give the current time today, and midnight otherwise
+        let now = Local::now();
+        let time = if self.date() == now.date_naive() {
+            now
+        } else {
+            Local
+                .from_local_datetime(&self.date().and_time(NaiveTime::MIN))
+                .earliest()
+                .ok_or_else(|| anyhow!("{self:#} has no midnight in the local time zone"))?
+        };
+        let mut x = String::new();
+        write!(x, "{}", time.format_with_items(items.into_iter()))
+            .map_err(|_| anyhow!("Couldn't format {self:#} with {fmt}"))?;
+        Ok(x)
+    }
+
+    fn date(self) -> NaiveDate {
+        self.to_date()
+            .unwrap_or_else(|| panic!("{self:#} is not a valid date"))
+    }
+}
+
+impl<T: Seasonlike> Ord for Seasonal<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.year_season()
+            .cmp(&other.year_season())
+            .then(self.week.cmp(&other.week))
+            .then_with(|| {
+                let days = week::system().days();
+                let position = |d| days.iter().position(|x| *x == d);
+                position(self.day).cmp(&position(other.day))
+            })
+    }
+}
+
+impl<T: Seasonlike> PartialOrd for Seasonal<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Panics if `self` isn't a valid date
+impl<T: Seasonlike> Add<Duration> for Seasonal<T> {
+    type Output = Seasonal<T>;
+    fn add(self, d: Duration) -> Seasonal<T> {
+        Seasonal::from_date(self.date() + d)
+    }
+}
+
+/// Panics if `self` isn't a valid date
+impl<T: Seasonlike> Sub<Duration> for Seasonal<T> {
+    type Output = Seasonal<T>;
+    fn sub(self, d: Duration) -> Seasonal<T> {
+        Seasonal::from_date(self.date() - d)
+    }
+}
+
+/// Panics if either date isn't valid
+impl<T: Seasonlike> Sub for Seasonal<T> {
+    type Output = Duration;
+    fn sub(self, other: Seasonal<T>) -> Duration {
+        self.date() - other.date()
+    }
+}
+
+/// Formats as "Obon-3 (Tue)".  The alternate form includes the year:
+/// "2025-Obon-3 (Tue)".
+impl<T: Seasonlike> Display for Seasonal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}-", self.year)?;
+        }
+        write!(f, "{}-{} ({})", self.season.name(), self.week, self.day)
+    }
+}
+
+impl<T: Seasonlike> Seasonal<T> {
+    /// Parses "Obon-3 (Tue)", "Obon-3 Tue", or "Obon-3-Tue", optionally
+    /// preceded by a year, as in "2025-Obon-3 Tue".  If no year is given,
+    /// `default_year` is used.
+    pub fn parse_with_default_year(txt: &str, default_year: i32) -> anyhow::Result<Seasonal<T>> {
+        let txt = txt.trim();
+        let (rest, day) = txt
+            .rsplit_once([' ', '-'])
+            .ok_or(anyhow!("Expected a date like \"Obon-3 Tue\""))?;
+        let day: Weekday = day
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .parse()
+            .map_err(|_| anyhow!("Unknown day of the week: {day}"))?;
+        let (rest, week) = rest
+            .trim_end()
+            .rsplit_once('-')
+            .ok_or(anyhow!("Expected a week number after the season"))?;
+        let week: u8 = week
+            .parse()
+            .map_err(|_| anyhow!("Bad week number: {week}"))?;
+        // The name's offset in the text, for errors
+        let (year, name, at) = match rest.split_once('-') {
+            Some((year, name)) if year.len() == 4 => {
+                let n = year.parse().map_err(|_| anyhow!("Bad year: {year}"))?;
+                (n, name, year.len() + 1)
+            }
+            _ => (default_year, rest, 0),
+        };
+        let season = season_named::<T>(name).map_err(|e| e.within(txt, at))?;
+        let date = Seasonal {
+            year,
+            season,
+            week,
+            day,
+        };
+        ensure!(
+            date.to_date().is_some(),
+            "{name} {year} doesn't have a week {week}"
+        );
+        Ok(date)
+    }
+}
+
+/// See `Seasonal::parse_with_default_year`.  If no year is given, the year of
+/// the current week is assumed.
+impl<T: Seasonlike> FromStr for Seasonal<T> {
+    type Err = anyhow::Error;
+    fn from_str(txt: &str) -> anyhow::Result<Seasonal<T>> {
+        let system = week::system();
+        Seasonal::parse_with_default_year(txt, system.number(system.this_week()).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every day converts to a seasonal date and back again
+    fn round_trip<T: Seasonlike>() {
+        let start = NaiveDate::from_ymd_opt(2015, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2035, 12, 31).unwrap();
+        for date in start.iter_days().take_while(|x| *x <= end) {
+            let seasonal = Seasonal::<T>::from_date(date);
+            assert_eq!(seasonal.to_date(), Some(date), "{seasonal:?}");
+        }
+    }
+
+    #[test]
+    fn round_trip_four() {
+        round_trip::<crate::four::Season>();
+    }
+
+    #[test]
+    fn round_trip_eight() {
+        round_trip::<crate::eight::Season>();
+    }
+
+    #[test]
+    fn round_trip_astro() {
+        round_trip::<crate::astro::Season>();
+    }
+
+    /// The fiscal settings are global and can only be set once, and reading
+    /// them sets them to their defaults, so this is the only test which may
+    /// use fiscal quarters or periods.  Tests run in parallel, and if another
+    /// test read the settings first, configuring them here would fail.
+    #[test]
+    fn fiscal() {
+        // A fiscal year which straddles two years
+        crate::fiscal::configure(4, Default::default()).unwrap();
+        round_trip::<crate::fiscal::Quarter>();
+        let date = NaiveDate::from_ymd_opt(2026, 2, 10).unwrap();
+        let seasonal = Seasonal::<crate::fiscal::Quarter>::from_date(date);
+        assert_eq!(format!("{seasonal:#}"), "2025-FQ4-7 (Tue)");
+        // FQ4 2025 falls in 2026, so it sorts between FQ3 2025 and FQ1 2026
+        use crate::fiscal::Quarter::*;
+        let ys = |year, season| YearSeason { year, season };
+        let mut xs = vec![ys(2026, FQ1), ys(2025, FQ4), ys(2025, FQ3)];
+        xs.sort();
+        assert_eq!(xs, [ys(2025, FQ3), ys(2025, FQ4), ys(2026, FQ1)]);
+        assert_eq!(ys(2025, FQ4).week_year(), 2026);
+        assert_eq!(ys(2025, FQ4) + 1, ys(2026, FQ1));
+        assert_eq!(ys(2026, FQ1) - 5, ys(2024, FQ4));
+        assert_eq!(ys(2025, FQ3).until(ys(2027, FQ3)).count(), 9);
+        assert!(seasonal < Seasonal::from_date(date + Duration::days(60)));
+        round_trip::<crate::fiscal::Period>();
+    }
+
+    #[test]
+    fn format_time() {
+        let date = NaiveDate::from_ymd_opt(2025, 8, 19).unwrap();
+        let date = Seasonal::<crate::eight::Season>::from_date(date);
+        let x = date.format("%Q-%K %H:%M", Hemisphere::North).unwrap();
+        assert_eq!(x, "Obon-1 00:00");
+    }
+
+    #[test]
+    fn weeks_past_the_end() {
+        let date = |week| Seasonal {
+            year: 2025,
+            season: crate::eight::Season::Obon,
+            week,
+            day: Weekday::Tue,
+        };
+        assert!(date(6).to_date().is_some());
+        assert_eq!(date(7).to_date(), None);
+        assert_eq!(date(250).to_date(), None);
+        assert_eq!(date(0).to_date(), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |txt| {
+            let x = Seasonal::<crate::eight::Season>::parse_with_default_year(txt, 2025);
+            x.unwrap_err().to_string()
+        };
+        assert_eq!(err("Obon-x-Tue"), "Bad week number: x");
+        assert_eq!(err("abcd-Obon-3 Tue"), "Bad year: abcd");
+        assert_eq!(err("Obon-3 Tus"), "Unknown day of the week: Tus");
+        assert_eq!(err("Obon-7 Tue"), "Obon 2025 doesn't have a week 7");
+    }
+
+    #[test]
+    fn arithmetic() {
+        use crate::four::Season::*;
+        let date = |year, season, week, day| Seasonal {
+            year,
+            season,
+            week,
+            day,
+        };
+        // Autumn 2026 has 14 weeks
+        let start = date(2026, Autumn, 1, Weekday::Mon);
+        let end = date(2027, Winter, 1, Weekday::Mon);
+        assert_eq!(start.weeks_until(end), 14);
+        assert_eq!(end.weeks_until(start), -14);
+        assert_eq!(
+            start + Duration::weeks(13),
+            date(2026, Autumn, 14, Weekday::Mon)
+        );
+        assert_eq!(
+            end - Duration::days(1),
+            date(2026, Autumn, 14, Weekday::Sun)
+        );
+        assert_eq!(end - start, Duration::weeks(14));
+        assert!(start < end);
+        assert!(date(2026, Autumn, 14, Weekday::Sun) < end);
+        assert!(date(2026, Autumn, 2, Weekday::Mon) > date(2026, Autumn, 1, Weekday::Sun));
+        let days = date(2026, Autumn, 14, Weekday::Sat).until(date(2027, Winter, 1, Weekday::Tue));
+        let days = days.map(|x| format!("{x:#}")).collect::<Vec<_>>();
+        assert_eq!(
+            days,
+            [
+                "2026-Autumn-14 (Sat)",
+                "2026-Autumn-14 (Sun)",
+                "2027-Winter-1 (Mon)",
+                "2027-Winter-1 (Tue)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! The 24 solar terms (sekki) and 72 micro-seasons (kō) of the traditional
+//! Japanese calendar.
+//!
+//! The year is divided by the sun's longitude: a new kō begins every 5°, and
+//! every third kō begins a new sekki.  Both are counted from 立春, when the sun
+//! reaches 315°.  Dates are in the local timezone.
+
+use crate::astro::solar_longitude;
+use chrono::{Local, NaiveDate, NaiveTime, TimeZone, Utc};
+
+const SEKKI_NAMES: [&str; 24] = [
+    "立春", "雨水", "啓蟄", "春分", "清明", "穀雨", "立夏", "小満", "芒種", "夏至", "小暑", "大暑",
+    "立秋", "処暑", "白露", "秋分", "寒露", "霜降", "立冬", "小雪", "大雪", "冬至", "小寒", "大寒",
+];
+
+#[rustfmt::skip]
+const KO_NAMES: [&str; 72] = [
+    "東風解凍", "黄鶯睍睆", "魚上氷", "土脉潤起", "霞始靆", "草木萌動",
+    "蟄虫啓戸", "桃始笑", "菜虫化蝶", "雀始巣", "櫻始開", "雷乃発声",
+    "玄鳥至", "鴻雁北", "虹始見", "葭始生", "霜止出苗", "牡丹華",
+    "蛙始鳴", "蚯蚓出", "竹笋生", "蚕起食桑", "紅花栄", "麦秋至",
+    "蟷螂生", "腐草為螢", "梅子黄", "乃東枯", "菖蒲華", "半夏生",
+    "温風至", "蓮始開", "鷹乃学習", "桐始結花", "土潤溽暑", "大雨時行",
+    "涼風至", "寒蝉鳴", "蒙霧升降", "綿柎開", "天地始粛", "禾乃登",
+    "草露白", "鶺鴒鳴", "玄鳥去", "雷乃収声", "蟄虫坏戸", "水始涸",
+    "鴻雁来", "菊花開", "蟋蟀在戸", "霜始降", "霎時施", "楓蔦黄",
+    "山茶始開", "地始凍", "金盞香", "虹蔵不見", "朔風払葉", "橘始黄",
+    "閉塞成冬", "熊蟄穴", "鱖魚群", "乃東生", "麋角解", "雪下出麦",
+    "芹乃栄", "水泉動", "雉始雊", "款冬華", "水沢腹堅", "鶏始乳",
+];
+
+/// One of the 24 solar terms, numbered from 立春
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Sekki(u8);
+
+/// One of the 72 micro-seasons, numbered from 東風解凍, the first kō of 立春
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Ko(u8);
+
+impl Sekki {
+    /// The sekki in effect at the end of the given day
+    pub fn on(date: NaiveDate) -> Sekki {
+        Ko::on(date).sekki()
+    }
+
+    /// The sekki which begins on the given day, if any
+    pub fn starting_on(date: NaiveDate) -> Option<Sekki> {
+        Ko::starting_on(date)
+            .filter(|ko| ko.0 % 3 == 0)
+            .map(Ko::sekki)
+    }
+
+    pub fn name(self) -> &'static str {
+        SEKKI_NAMES[self.0 as usize]
+    }
+}
+
+impl Ko {
+    /// The kō in effect at the end of the given day
+    pub fn on(date: NaiveDate) -> Ko {
+        let midnight = date
+            .succ_opt()
+            .unwrap()
+            .and_time(NaiveTime::MIN)
+            .and_local_timezone(Local)
+            .earliest()
+            .map_or_else(
+                || Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)),
+                |x| x.with_timezone(&Utc),
+            );
+        let since_risshun = (solar_longitude(midnight) - 315.0).rem_euclid(360.0);
+        Ko((since_risshun / 5.0) as u8 % 72)
+    }
+
+    /// The kō which begins on the given day, if any
+    pub fn starting_on(date: NaiveDate) -> Option<Ko> {
+        let ko = Ko::on(date);
+        (Ko::on(date.pred_opt()?) != ko).then_some(ko)
+    }
+
+    /// The sekki which this kō is part of
+    pub fn sekki(self) -> Sekki {
+        Sekki(self.0 / 3)
+    }
+
+    pub fn name(self) -> &'static str {
+        KO_NAMES[self.0 as usize]
+    }
+}
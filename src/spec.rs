@@ -1,105 +1,659 @@
-use crate::{eight::Season, YearSeason};
+use crate::{week, Hemisphere, Seasonlike, YearSeason};
 use anyhow::{anyhow, ensure};
-use chrono::{Datelike, IsoWeek, Local, Month, NaiveDate, Weekday};
+use chrono::{Datelike, Duration, IsoWeek, Local, Month, NaiveDate, Weekday};
+use std::fmt::{self, Display};
+use std::ops::{Range, RangeInclusive};
 use std::str::FromStr;
 
-enum DateObject {
+/// A period of time named on the command line, such as a year, a month, or a
+/// season
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DateObject {
     Year(i32),
     Month(i32, Month),
-    Week(i32, u8),
+    Week(i32, u32),
     Day(NaiveDate),
-    Season(YearSeason<Season>),
+    /// A season, looked up by name in the calendar in use
+    Season(i32, String),
+    /// The period of the given length which is the given number of periods
+    /// from the current one, eg. "+2s" for the season after next
+    Relative(i32, Unit),
+}
+
+/// The lengths of time which relative specs can be given in
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Unit {
+    Day,
+    Week,
+    Month,
+    Season,
+    Year,
+}
+
+const UNIT_NAMES: [&str; 10] = [
+    "d", "day", "w", "week", "m", "month", "s", "season", "y", "year",
+];
+
+impl FromStr for Unit {
+    type Err = ParseError;
+    fn from_str(txt: &str) -> Result<Unit, ParseError> {
+        match txt {
+            "d" | "day" => Ok(Unit::Day),
+            "w" | "week" => Ok(Unit::Week),
+            "m" | "month" => Ok(Unit::Month),
+            "s" | "season" => Ok(Unit::Season),
+            "y" | "year" => Ok(Unit::Year),
+            _ => Err(ParseError::new(
+                txt,
+                0..txt.len(),
+                ParseErrorKind::UnknownUnit,
+            )),
+        }
+    }
+}
+
+/// Something wrong with a spec, and where in the spec it is
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// The text which was being parsed
+    pub input: String,
+    /// The bytes of the input which are wrong
+    pub span: Range<usize>,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErrorKind {
+    Empty,
+    /// Not any of the forms which a spec can take
+    Unrecognised,
+    BadNumber,
+    /// A week number in an ISO 8601 date which isn't two digits
+    NotTwoDigits,
+    UnknownUnit,
+    NoSuchMonth,
+    NoSuchWeek {
+        year: i32,
+        number: u32,
+    },
+    /// A day of the week outside 1 to 7
+    NoSuchDay,
+    NoSuchDate,
+    /// The known seasons are those of the calendar in use
+    UnknownSeason {
+        known: Vec<&'static str>,
+    },
+    /// An abbreviation which could be any of several seasons
+    AmbiguousSeason {
+        matches: Vec<&'static str>,
+    },
+    /// A longer period, where a single week is needed
+    NotAWeek,
+    TooManyDots,
+    /// An event with nothing after its week
+    NoDescription,
+}
+
+impl ParseError {
+    pub fn new(input: &str, span: Range<usize>, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            input: input.to_string(),
+            span,
+            kind,
+        }
+    }
+
+    /// Place an error in a part of a larger input, which starts at the given
+    /// byte offset
+    pub fn within(self, input: &str, offset: usize) -> ParseError {
+        ParseError {
+            input: input.to_string(),
+            span: self.span.start + offset..self.span.end + offset,
+            kind: self.kind,
+        }
+    }
+
+    /// The offending part of the input
+    pub fn found(&self) -> &str {
+        &self.input[self.span.clone()]
+    }
+
+    /// A nearby name which was probably meant
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match &self.kind {
+            ParseErrorKind::UnknownUnit => suggest(self.found(), UNIT_NAMES),
+            ParseErrorKind::UnknownSeason { known } => {
+                let months = (1..=12).map(|m| Month::try_from(m).unwrap().name());
+                suggest(self.found(), known.iter().copied().chain(months))
+            }
+            _ => None,
+        }
+    }
+
+    /// The forms which would have been accepted instead
+    pub fn expected(&self) -> Option<String> {
+        use ParseErrorKind::*;
+        let x = match &self.kind {
+            Empty | Unrecognised => {
+                "a year (2025), month (2025-03, mar), week (w12, 2025-W12), \
+                date (2025-03-14, 2025-073), season (2025-spring), or relative period (today, \
+                next-season, +2w)"
+            }
+            BadNumber | NoSuchDate => return None,
+            NotTwoDigits => "a two-digit week number, eg. 2025-W05",
+            UnknownUnit => "d, w, m, s, or y (or day, week, month, season, or year)",
+            NoSuchMonth => "a month from 1 to 12",
+            NoSuchWeek { year, .. } => {
+                let system = week::system();
+                let weeks = system.year(*year)?;
+                let last = system.number(*weeks.end()).1;
+                return Some(format!("a week from w1 to w{last}"));
+            }
+            NoSuchDay => "a day of the week from 1 (Monday) to 7 (Sunday)",
+            UnknownSeason { known } => return Some(format!("one of {}", known.join(", "))),
+            AmbiguousSeason { .. } => "more of the season's name",
+            NotAWeek => "a week (w12, 2025-W12) or a date (2025-03-14)",
+            TooManyDots => "a single period, or a range such as 2025-03..2025-06",
+            NoDescription => "a description after the week",
+        };
+        Some(x.to_string())
+    }
+}
+
+/// The message, then the input with the offending part underlined, then the
+/// forms which were expected
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseErrorKind::*;
+        let found = self.found();
+        match &self.kind {
+            Empty => write!(f, "Empty spec")?,
+            Unrecognised => write!(f, "Couldn't understand '{found}'")?,
+            BadNumber => write!(f, "Expected a number, but found '{found}'")?,
+            NotTwoDigits => write!(f, "Expected two digits, but found '{found}'")?,
+            UnknownUnit => write!(f, "Unknown unit '{found}'")?,
+            NoSuchMonth => write!(f, "There is no month {found}")?,
+            NoSuchWeek { year, number } => write!(f, "There is no w{number} in {year}")?,
+            NoSuchDay => write!(f, "There is no day {found} of the week")?,
+            NoSuchDate => write!(f, "There is no date {found}")?,
+            UnknownSeason { .. } => write!(f, "Unknown season '{found}'")?,
+            AmbiguousSeason { matches } => {
+                write!(f, "'{found}' could be any of {}", matches.join(", "))?
+            }
+            NotAWeek => write!(f, "Expected a week or a date, but found '{found}'")?,
+            TooManyDots => write!(f, "Too many '..' in '{found}'")?,
+            NoDescription => write!(f, "The event has no description")?,
+        }
+        if let Some(x) = self.suggestion() {
+            write!(f, ", did you mean '{x}'?")?;
+        }
+        if self.span != (0..self.input.len()) {
+            let column = self.input[..self.span.start].chars().count();
+            let width = found.chars().count().max(1);
+            write!(
+                f,
+                "\n    {}\n    {}{}",
+                self.input,
+                " ".repeat(column),
+                "^".repeat(width)
+            )?;
+        }
+        if let Some(x) = self.expected() {
+            write!(f, "\n  expected {x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The number of single-character edits which turn one word into the other,
+/// ignoring case
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.to_lowercase().chars().collect::<Vec<_>>();
+    let b = b.to_lowercase().chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    for (i, x) in a.iter().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, y) in b.iter().enumerate() {
+            row.push(
+                (prev[j] + usize::from(x != y))
+                    .min(prev[j + 1] + 1)
+                    .min(row[j] + 1),
+            );
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+/// The candidate closest to the given word, if it's close enough to have been
+/// a typo for it
+fn suggest<'a>(word: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|x| (edit_distance(word, x), x))
+        .filter(|(d, x)| *d <= (x.chars().count() / 3).max(1) && *d < word.chars().count())
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, x)| x)
+}
+
+/// Look up a season of the given calendar by name, or by an abbreviation of it
+pub fn season_named<S: Seasonlike>(name: &str) -> Result<S, ParseError> {
+    let kind = match S::matching(name)[..] {
+        [x] => return Ok(x),
+        [] => ParseErrorKind::UnknownSeason {
+            known: S::all().into_iter().map(|s| s.name()).collect(),
+        },
+        ref xs => ParseErrorKind::AmbiguousSeason {
+            matches: xs.iter().map(|s| s.name()).collect(),
+        },
+    };
+    Err(ParseError::new(name, 0..name.len(), kind))
+}
+
+fn digits(x: &str) -> bool {
+    !x.is_empty() && x.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse relative specs: "today", "this-season", "next-month", "prev-week",
+/// "+3w", "-2s", and so on
+fn parse_relative(txt: &str) -> Option<Result<DateObject, ParseError>> {
+    if txt == "today" {
+        return Some(Ok(DateObject::Relative(0, Unit::Day)));
+    }
+    let (n, unit) = if let Some(unit) = txt.strip_prefix("this-") {
+        (0, unit)
+    } else if let Some(unit) = txt.strip_prefix("next-") {
+        (1, unit)
+    } else if let Some(unit) = txt.strip_prefix("prev-") {
+        (-1, unit)
+    } else if txt.starts_with(['+', '-']) {
+        let split = txt.find(|c: char| c.is_ascii_alphabetic())?;
+        let (n, unit) = txt.split_at(split);
+        match n.parse::<i32>() {
+            Ok(n) => (n, unit),
+            Err(_) => {
+                return Some(Err(ParseError::new(
+                    txt,
+                    0..split,
+                    ParseErrorKind::BadNumber,
+                )))
+            }
+        }
+    } else {
+        return None;
+    };
+    let offset = txt.len() - unit.len();
+    Some(
+        unit.parse()
+            .map(|unit| DateObject::Relative(n, unit))
+            .map_err(|e: ParseError| e.within(txt, offset)),
+    )
+}
+
+/// Parse the ISO 8601 forms which start with a year and aren't separated into
+/// tokens by hyphens: week dates (2025-W05, 2025W05, 2025-W05-3, 2025W053),
+/// ordinal dates (2025-045, 2025045), and basic calendar dates (20250314).
+/// Weeks are numbered using the configured week system, which is ISO 8601 by
+/// default.
+fn parse_iso8601(txt: &str) -> Option<Result<DateObject, ParseError>> {
+    let err = |span, kind| Some(Err(ParseError::new(txt, span, kind)));
+    let year = txt.get(..4).filter(|x| digits(x))?.parse::<i32>().ok()?;
+    let rest = &txt[4..];
+    let extended = rest.starts_with('-');
+    let rest = rest.strip_prefix('-').unwrap_or(rest);
+    if let Some(w) = rest.strip_prefix(['W', 'w']) {
+        let (week, day) = match w.split_once('-') {
+            Some((week, day)) if extended => (week, Some(day)),
+            None if w.len() == 3 && !extended && digits(w) => (&w[..2], Some(&w[2..])),
+            None => (w, None),
+            Some(_) => return None,
+        };
+        if !digits(week) || !day.is_none_or(digits) {
+            return None;
+        }
+        let at = txt.len() - w.len();
+        // Week numbers have two digits, so 2025-W053 isn't week 53
+        if week.len() > 2 {
+            return err(at..at + week.len(), ParseErrorKind::NotTwoDigits);
+        }
+        let number = week.parse().ok()?;
+        let Some(week) = week::system().week(year, number) else {
+            return err(
+                at..at + week.len(),
+                ParseErrorKind::NoSuchWeek { year, number },
+            );
+        };
+        return match day.map(|d| d.parse::<u8>()) {
+            None => Some(Ok(DateObject::Week(year, number))),
+            Some(Ok(d @ 1..=7)) => {
+                let day = Weekday::try_from(d - 1).unwrap();
+                Some(Ok(DateObject::Day(week::system().date(week, day))))
+            }
+            Some(_) => err(
+                txt.len() - day.unwrap().len()..txt.len(),
+                ParseErrorKind::NoSuchDay,
+            ),
+        };
+    }
+    let result = match rest.len() {
+        // Ordinal dates
+        3 if digits(rest) => NaiveDate::from_yo_opt(year, rest.parse().ok()?),
+        // Basic calendar dates
+        4 if digits(rest) && !extended => {
+            NaiveDate::from_ymd_opt(year, rest[..2].parse().ok()?, rest[2..].parse().ok()?)
+        }
+        _ => return None,
+    };
+    match result {
+        Some(x) => Some(Ok(DateObject::Day(x))),
+        None => err(0..txt.len(), ParseErrorKind::NoSuchDate),
+    }
 }
 
 impl FromStr for DateObject {
-    type Err = anyhow::Error;
-    fn from_str(txt: &str) -> anyhow::Result<DateObject> {
-        let mut tokens: Vec<&str> = txt.split('-').collect();
-        if tokens.is_empty() {
-            return Err(anyhow!("Empty string"));
-        }
-        let year = match tokens[0].parse() {
-            Ok(x) if tokens[0].len() == 4 => {
+    type Err = ParseError;
+    fn from_str(txt: &str) -> Result<DateObject, ParseError> {
+        let err = |span, kind| Err(ParseError::new(txt, span, kind));
+        if txt.is_empty() {
+            return err(0..0, ParseErrorKind::Empty);
+        }
+        if let Some(x) = parse_relative(txt).or_else(|| parse_iso8601(txt)) {
+            return x;
+        }
+        // The tokens, with their byte offsets
+        let mut tokens = vec![];
+        let mut at = 0;
+        for token in txt.split('-') {
+            tokens.push((at, token));
+            at += token.len() + 1;
+        }
+        let year = match tokens[0].1.parse() {
+            Ok(x) if tokens[0].1.len() == 4 => {
                 tokens.remove(0);
                 x
             }
             _ => Local::now().year(),
         };
-        Ok(if tokens.is_empty() {
-            DateObject::Year(year)
-        } else if let Ok(month) = tokens[0].parse::<Month>() {
-            DateObject::Month(year, month)
-        } else if let Ok(season) = tokens[0].parse::<Season>() {
-            DateObject::Season(YearSeason { year, season })
-        } else if let Some(week) = tokens[0].strip_prefix('w') {
-            DateObject::Week(year, week.parse().unwrap())
-        } else {
-            let month = tokens[0].parse()?;
-            let day = tokens[1].parse()?;
-            DateObject::Day(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+        let number = |(at, x): (usize, &str)| {
+            x.parse::<u32>()
+                .map_err(|_| ParseError::new(txt, at..at + x.len(), ParseErrorKind::BadNumber))
+        };
+        Ok(match tokens[..] {
+            [] => DateObject::Year(year),
+            [(at, x)] if x.starts_with(['w', 'W']) && digits(&x[1..]) => {
+                let number = number((at + 1, &x[1..]))?;
+                if week::system().week(year, number).is_none() {
+                    return err(
+                        at..at + x.len(),
+                        ParseErrorKind::NoSuchWeek { year, number },
+                    );
+                }
+                DateObject::Week(year, number)
+            }
+            [(at, x)] if digits(x) => {
+                let month = x.parse::<u8>().ok().and_then(|m| Month::try_from(m).ok());
+                match month {
+                    Some(month) => DateObject::Month(year, month),
+                    None => return err(at..at + x.len(), ParseErrorKind::NoSuchMonth),
+                }
+            }
+            [(at, x)] => {
+                if let Ok(month) = x.parse::<Month>() {
+                    DateObject::Month(year, month)
+                } else if x.is_empty() {
+                    return err(at..at, ParseErrorKind::Unrecognised);
+                } else {
+                    DateObject::Season(year, x.to_string())
+                }
+            }
+            [month, day] => {
+                let date = NaiveDate::from_ymd_opt(year, number(month)?, number(day)?);
+                match date {
+                    Some(x) => DateObject::Day(x),
+                    None => return err(0..txt.len(), ParseErrorKind::NoSuchDate),
+                }
+            }
+            _ => return err(0..txt.len(), ParseErrorKind::Unrecognised),
         })
     }
 }
 
-fn new_week(year: i32, week: u32) -> anyhow::Result<IsoWeek> {
-    Ok(NaiveDate::from_isoywd_opt(year, week, Weekday::Wed)
-        .ok_or(anyhow!("There is no w{} in {}", week, year))?
-        .iso_week())
+impl DateObject {
+    /// The weeks which overlap with this period.  Seasons are looked up in
+    /// the given calendar, by their names in the given hemisphere.
+    pub fn weeks<S: Seasonlike>(
+        &self,
+        hemisphere: Hemisphere,
+    ) -> anyhow::Result<RangeInclusive<IsoWeek>> {
+        let system = week::system();
+        let days = |start: Option<NaiveDate>, end: Option<NaiveDate>| {
+            let start = start.ok_or_else(|| anyhow!("Date out of range"))?;
+            let end = end.ok_or_else(|| anyhow!("Date out of range"))?;
+            Ok(system.week_of(start)..=system.week_of(end))
+        };
+        match *self {
+            DateObject::Year(year) => days(
+                NaiveDate::from_ymd_opt(year, 1, 1),
+                NaiveDate::from_ymd_opt(year, 12, 31),
+            ),
+            DateObject::Month(year, month) => {
+                let start = NaiveDate::from_ymd_opt(year, month.number_from_month(), 1);
+                let end = match month {
+                    Month::December => NaiveDate::from_ymd_opt(year + 1, 1, 1),
+                    _ => NaiveDate::from_ymd_opt(year, month.succ().number_from_month(), 1),
+                };
+                days(start, end.and_then(|x| x.pred_opt()))
+            }
+            DateObject::Week(year, number) => {
+                let week = system
+                    .week(year, number)
+                    .ok_or_else(|| anyhow!("There is no w{number} in {year}"))?;
+                Ok(week..=week)
+            }
+            DateObject::Day(date) => Ok(system.week_of(date)..=system.week_of(date)),
+            DateObject::Relative(n, unit) => {
+                let today = Local::now().date_naive();
+                match unit {
+                    Unit::Day => {
+                        let date = today.checked_add_signed(Duration::days(n.into()));
+                        days(date, date)
+                    }
+                    Unit::Week => {
+                        let date = today.checked_add_signed(Duration::weeks(n.into()));
+                        days(date, date)
+                    }
+                    Unit::Month => {
+                        let months = (today.year() * 12 + today.month0() as i32)
+                            .checked_add(n)
+                            .ok_or_else(|| anyhow!("Date out of range"))?;
+                        let month = Month::try_from(months.rem_euclid(12) as u8 + 1).unwrap();
+                        DateObject::Month(months.div_euclid(12), month).weeks::<S>(hemisphere)
+                    }
+                    Unit::Season => YearSeason::<S>::now()
+                        .checked_add(n)
+                        .map(YearSeason::weeks)
+                        .ok_or_else(|| anyhow!("Date out of range")),
+                    Unit::Year => {
+                        let year = today
+                            .year()
+                            .checked_add(n)
+                            .ok_or_else(|| anyhow!("Date out of range"))?;
+                        DateObject::Year(year).weeks::<S>(hemisphere)
+                    }
+                }
+            }
+            DateObject::Season(year, ref name) => {
+                let season = season_named::<S>(name)?;
+                let season = match hemisphere {
+                    Hemisphere::North => season,
+                    Hemisphere::South => season.flip_hemisphere(),
+                };
+                Ok(YearSeason { year, season }.weeks())
+            }
+        }
+    }
 }
 
-pub fn parse_one_week(i: &str) -> anyhow::Result<IsoWeek> {
-    match i.chars().next() {
-        None => Err(anyhow!("Empty string")),
-        Some('w' | 'W') => {
-            let w = i[1..].parse()?;
-            new_week(Local::now().year(), w)
-        }
-        _ => {
-            let y: i32 = i
-                .get(..4)
-                .ok_or(anyhow!("Expected a 4-digit year"))?
-                .parse()?;
-            ensure!(i.get(4..6) == Some("-w"));
-            let w: u32 = i[6..].parse()?;
-            new_week(y, w)
-        }
+/// Parse a single week, given either as a week or as a day within it
+pub fn parse_one_week(i: &str) -> Result<IsoWeek, ParseError> {
+    match i.parse()? {
+        DateObject::Week(year, number) => week::system().week(year, number).ok_or_else(|| {
+            ParseError::new(i, 0..i.len(), ParseErrorKind::NoSuchWeek { year, number })
+        }),
+        DateObject::Day(date) => Ok(week::system().week_of(date)),
+        _ => Err(ParseError::new(i, 0..i.len(), ParseErrorKind::NotAWeek)),
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum WeeksSpec {
-    Single(IsoWeek),
-    Range(IsoWeek, IsoWeek),
-    From(IsoWeek),
-    To(IsoWeek),
+    Single(DateObject),
+    Range(DateObject, DateObject),
+    From(DateObject),
+    To(DateObject),
 }
 
 impl WeeksSpec {
-    pub fn range(&self) -> std::ops::RangeInclusive<IsoWeek> {
-        let this_week = || Local::now().iso_week();
-        match *self {
-            WeeksSpec::Single(x) => x..=x,
-            WeeksSpec::Range(from, to) => from..=to,
-            WeeksSpec::From(from) => from..=this_week(),
-            WeeksSpec::To(to) => this_week()..=to,
+    /// Parse a spec, checking that the seasons it names are in the given
+    /// calendar
+    pub fn parse<S: Seasonlike>(i: &str) -> Result<WeeksSpec, ParseError> {
+        let spec = i.parse::<WeeksSpec>()?;
+        let (from, to) = match &spec {
+            WeeksSpec::Single(x) | WeeksSpec::From(x) => (Some(x), None),
+            WeeksSpec::Range(from, to) => (Some(from), Some(to)),
+            WeeksSpec::To(x) => (None, Some(x)),
+        };
+        // Season names come at the end of their part of the spec
+        let from_end = i.find("..").unwrap_or(i.len());
+        for (x, end) in [(from, from_end), (to, i.len())] {
+            if let Some(DateObject::Season(_, name)) = x {
+                season_named::<S>(name).map_err(|e| e.within(i, end - name.len()))?;
+            }
         }
+        Ok(spec)
+    }
+
+    /// The weeks from the start of the first period to the end of the last.
+    /// Open ranges run to or from the current week.
+    pub fn range<S: Seasonlike>(
+        &self,
+        hemisphere: Hemisphere,
+    ) -> anyhow::Result<RangeInclusive<IsoWeek>> {
+        let system = week::system();
+        let this_week = system.this_week();
+        let weeks = |x: &DateObject| x.weeks::<S>(hemisphere);
+        let range = match self {
+            WeeksSpec::Single(x) => weeks(x)?,
+            WeeksSpec::Range(from, to) => *weeks(from)?.start()..=*weeks(to)?.end(),
+            WeeksSpec::From(from) => *weeks(from)?.start()..=this_week,
+            WeeksSpec::To(to) => this_week..=*weeks(to)?.end(),
+        };
+        let label = |x: IsoWeek| {
+            let (year, n) = system.number(x);
+            format!("{year}-w{n:02}")
+        };
+        ensure!(
+            range.start() <= range.end(),
+            "The range ends ({}) before it starts ({}).  Periods without a year are in \
+             the current year.",
+            label(*range.end()),
+            label(*range.start()),
+        );
+        Ok(range)
     }
 }
 
 impl FromStr for WeeksSpec {
-    type Err = anyhow::Error;
-    fn from_str(i: &str) -> anyhow::Result<WeeksSpec> {
+    type Err = ParseError;
+    fn from_str(i: &str) -> Result<WeeksSpec, ParseError> {
         let xs = i.split("..").collect::<Vec<_>>();
+        // Errors are reported against the whole spec
+        let parse = |x: &str, offset| x.parse().map_err(|e: ParseError| e.within(i, offset));
         match xs[..] {
-            [x] => parse_one_week(x).map(WeeksSpec::Single),
-            [from, ""] => parse_one_week(from).map(WeeksSpec::From),
-            ["", to] => parse_one_week(to).map(WeeksSpec::To),
-            [from, to] => {
-                let from = parse_one_week(from)?;
-                let to = parse_one_week(to)?;
-                Ok(WeeksSpec::Range(from, to))
-            }
-            _ => Err(anyhow!("Too many weeks given")),
+            [x] => parse(x, 0).map(WeeksSpec::Single),
+            [from, ""] => parse(from, 0).map(WeeksSpec::From),
+            ["", to] => parse(to, 2).map(WeeksSpec::To),
+            [from, to] => Ok(WeeksSpec::Range(
+                parse(from, 0)?,
+                parse(to, from.len() + 2)?,
+            )),
+            _ => Err(ParseError::new(i, 0..i.len(), ParseErrorKind::TooManyDots)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> DateObject {
+        DateObject::Day(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    fn kind(txt: &str) -> ParseErrorKind {
+        txt.parse::<DateObject>().unwrap_err().kind
+    }
+
+    #[test]
+    fn iso8601_week_dates() {
+        let parse = |x: &str| x.parse::<DateObject>().unwrap();
+        assert_eq!(parse("2025-W05"), DateObject::Week(2025, 5));
+        assert_eq!(parse("2025W05"), DateObject::Week(2025, 5));
+        assert_eq!(parse("2025-w05"), DateObject::Week(2025, 5));
+        assert_eq!(parse("2025-W05-3"), ymd(2025, 1, 29));
+        assert_eq!(parse("2025W053"), ymd(2025, 1, 29));
+        assert_eq!(parse("2026-W53-7"), ymd(2027, 1, 3));
+    }
+
+    #[test]
+    fn iso8601_ordinal_and_calendar_dates() {
+        let parse = |x: &str| x.parse::<DateObject>().unwrap();
+        assert_eq!(parse("2025-045"), ymd(2025, 2, 14));
+        assert_eq!(parse("2025045"), ymd(2025, 2, 14));
+        assert_eq!(parse("2024-366"), ymd(2024, 12, 31));
+        assert_eq!(parse("20250314"), ymd(2025, 3, 14));
+        assert_eq!(parse("2025-03-14"), ymd(2025, 3, 14));
+    }
+
+    #[test]
+    fn iso8601_rejected() {
+        assert_eq!(kind("2025-W053"), ParseErrorKind::NotTwoDigits);
+        assert_eq!(
+            kind("2025-W53"),
+            ParseErrorKind::NoSuchWeek {
+                year: 2025,
+                number: 53
+            }
+        );
+        assert_eq!(kind("2025-W05-8"), ParseErrorKind::NoSuchDay);
+        assert_eq!(kind("2025W050"), ParseErrorKind::NoSuchDay);
+        assert_eq!(kind("2025-366"), ParseErrorKind::NoSuchDate);
+        assert_eq!(kind("20250230"), ParseErrorKind::NoSuchDate);
+        assert!("2025W05-3".parse::<DateObject>().is_err());
+        assert!(WeeksSpec::parse::<crate::eight::Season>("2025W1é").is_err());
+        assert!(parse_one_week("2026W4é").is_err());
+    }
+
+    #[test]
+    fn error_spans() {
+        let err = "2025-W05-8".parse::<DateObject>().unwrap_err();
+        assert_eq!(err.found(), "8");
+        let err = "2025-03..2025-W053".parse::<WeeksSpec>().unwrap_err();
+        assert_eq!(err.span, 15..18);
+    }
+
+    #[test]
+    fn season_errors() {
+        type Eight = crate::eight::Season;
+        let err = WeeksSpec::parse::<Eight>("2025-Obom..2025-Advent").unwrap_err();
+        assert_eq!(err.span, 5..9);
+        assert_eq!(err.suggestion(), Some("Obon"));
+        let err = WeeksSpec::parse::<Eight>("Obon..Adbent").unwrap_err();
+        assert_eq!(err.span, 6..12);
+        assert_eq!(err.suggestion(), Some("Advent"));
+        let err = WeeksSpec::parse::<Eight>("s..Obon").unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::AmbiguousSeason { .. }));
+    }
+}
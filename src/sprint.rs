@@ -0,0 +1,39 @@
+//! Fixed-length sprints, numbered from an epoch.  The sprint containing the
+//! epoch is sprint 1.
+
+use crate::week::monday;
+use chrono::{Datelike, Duration, IsoWeek, NaiveDate};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sprints {
+    /// The first sprint starts on the week containing this date
+    pub epoch: NaiveDate,
+    /// The length of a sprint, in weeks
+    pub length: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sprint {
+    pub number: i64,
+    /// The week of the sprint, starting from 1
+    pub week: u32,
+}
+
+impl Sprints {
+    pub fn sprint(&self, week: IsoWeek) -> Sprint {
+        let weeks = (monday(week) - monday(crate::week::system().week_of(self.epoch))).num_weeks();
+        let length = i64::from(self.length);
+        Sprint {
+            number: weeks.div_euclid(length) + 1,
+            week: weeks.rem_euclid(length) as u32 + 1,
+        }
+    }
+
+    /// The weeks of the given sprint
+    pub fn weeks(&self, number: i64) -> std::ops::RangeInclusive<IsoWeek> {
+        let start = monday(crate::week::system().week_of(self.epoch))
+            + Duration::weeks((number - 1) * i64::from(self.length));
+        let end = start + Duration::weeks(i64::from(self.length) - 1);
+        start.iso_week()..=end.iso_week()
+    }
+}
@@ -0,0 +1,86 @@
+//! Sunrise and sunset, using the NOAA solar calculator's equations.  The
+//! results are good to about a minute for latitudes between ±72°.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Location {
+    /// Degrees north
+    pub latitude: f64,
+    /// Degrees east
+    pub longitude: f64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Daylight {
+    Sun {
+        sunrise: DateTime<Utc>,
+        sunset: DateTime<Utc>,
+    },
+    /// The sun doesn't set
+    PolarDay,
+    /// The sun doesn't rise
+    PolarNight,
+}
+
+impl Daylight {
+    /// The time between sunrise and sunset
+    pub fn length(self) -> Duration {
+        match self {
+            Daylight::Sun { sunrise, sunset } => sunset - sunrise,
+            Daylight::PolarDay => Duration::days(1),
+            Daylight::PolarNight => Duration::zero(),
+        }
+    }
+}
+
+/// Sunrise and sunset at the given place on the given day.  The day is
+/// counted from midnight to midnight local solar time.
+pub fn daylight(date: NaiveDate, location: Location) -> Daylight {
+    let midnight = Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN));
+    // Julian centuries since J2000.0, at local noon
+    let jd = midnight.timestamp() as f64 / 86400.0 + 2440587.5 + 0.5 - location.longitude / 360.0;
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let l0 = (280.46646 + t * (36000.76983 + t * 0.0003032)).rem_euclid(360.0);
+    let m = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let e = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+    let c = m.to_radians().sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).to_radians().sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).to_radians().sin() * 0.000289;
+    let omega = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude = l0 + c - 0.00569 - 0.00478 * omega.sin();
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.00059 - t * 0.001813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.00256 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.to_radians().sin()).asin();
+
+    // The equation of time, in minutes
+    let y = (obliquity / 2.0).tan().powi(2);
+    let (l0, m) = (l0.to_radians(), m.to_radians());
+    let eq_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * e * m.sin() + 4.0 * e * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * e * e * (2.0 * m).sin())
+        .to_degrees();
+
+    // The sun's centre is 0.833° below the horizon at sunrise, allowing for
+    // refraction and the size of its disc
+    let latitude = location.latitude.to_radians();
+    let cos_hour_angle = 90.833f64.to_radians().cos() / (latitude.cos() * declination.cos())
+        - latitude.tan() * declination.tan();
+    if cos_hour_angle > 1.0 {
+        return Daylight::PolarNight;
+    } else if cos_hour_angle < -1.0 {
+        return Daylight::PolarDay;
+    }
+    let hour_angle = cos_hour_angle.acos().to_degrees();
+
+    // In minutes after midnight UTC
+    let noon = 720.0 - 4.0 * location.longitude - eq_time;
+    let at = |mins: f64| midnight + Duration::seconds((mins * 60.0).round() as i64);
+    Daylight::Sun {
+        sunrise: at(noon - 4.0 * hour_angle),
+        sunset: at(noon + 4.0 * hour_angle),
+    }
+}
@@ -0,0 +1,246 @@
+//! Academic terms, loaded from `~/.config/wcal/terms`.
+//!
+//! Each line defines either a term or a break within a term, by its first
+//! and last days:
+//!
+//! ```text
+//! term   2025-10-09  2025-12-05  Michaelmas
+//! break  2025-11-10  2025-11-14  Reading week
+//! term   2026-01-15  2026-03-13  Lent
+//! ```
+//!
+//! Weeks of a term are numbered as "teaching weeks", starting from 1 on the
+//! week the term starts.  Weeks whose weekdays are all in a break aren't
+//! teaching weeks, and aren't counted.
+
+use crate::week::system;
+use anyhow::{anyhow, bail, ensure, Context};
+use chrono::{Datelike, IsoWeek, NaiveDate};
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Term {
+    pub name: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl Term {
+    /// The weeks from the start of the term to its end
+    pub fn weeks(&self) -> std::ops::RangeInclusive<IsoWeek> {
+        system().week_of(self.start)..=system().week_of(self.end)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Break {
+    pub name: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Terms {
+    terms: Vec<Term>,
+    breaks: Vec<Break>,
+}
+
+impl Terms {
+    pub fn path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("wcal/terms"))
+    }
+
+    /// Load the terms from the user's config directory
+    pub fn load() -> anyhow::Result<Terms> {
+        let path = Terms::path().ok_or(anyhow!("Couldn't find the config directory"))?;
+        let txt = std::fs::read_to_string(&path)
+            .with_context(|| format!("Couldn't read {}", path.display()))?;
+        Terms::parse(&txt).with_context(|| format!("Invalid terms in {}", path.display()))
+    }
+
+    pub fn parse(txt: &str) -> anyhow::Result<Terms> {
+        let mut terms: Vec<Term> = vec![];
+        let mut breaks = vec![];
+        for (i, line) in txt.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+            let lineno = i + 1;
+            let mut rest = line;
+            let mut field = || {
+                let (x, xs) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                rest = xs.trim_start();
+                x
+            };
+            let (kind, start, end) = (field(), field(), field());
+            let start = NaiveDate::parse_from_str(start, "%Y-%m-%d")
+                .with_context(|| format!("line {lineno}: bad start date"))?;
+            let end = NaiveDate::parse_from_str(end, "%Y-%m-%d")
+                .with_context(|| format!("line {lineno}: bad end date"))?;
+            let name = rest.to_string();
+            ensure!(start <= end, "line {lineno}: {name} ends before it starts");
+            match kind {
+                "term" => {
+                    if let Some(t) = terms.iter().find(|t| t.start <= end && start <= t.end) {
+                        bail!("line {lineno}: {name} overlaps {}", t.name);
+                    }
+                    terms.push(Term { name, start, end });
+                }
+                "break" => breaks.push(Break { name, start, end }),
+                _ => bail!("line {lineno}: expected \"term\" or \"break\", not {kind}"),
+            }
+        }
+        for b in &breaks {
+            ensure!(
+                terms.iter().any(|t| t.start <= b.start && b.end <= t.end),
+                "The break {} ({} to {}) isn't within a term",
+                b.name,
+                b.start,
+                b.end
+            );
+        }
+        terms.sort_by_key(|t| t.start);
+        Ok(Terms { terms, breaks })
+    }
+
+    /// The term which the given week is part of, if any
+    pub fn term(&self, week: IsoWeek) -> Option<&Term> {
+        self.terms
+            .iter()
+            .find(|t| system().dates(week).any(|d| t.start <= d && d <= t.end))
+    }
+
+    /// The term containing the given date, or else the next one to start
+    pub fn current_or_next(&self, date: NaiveDate) -> Option<&Term> {
+        self.terms.iter().find(|t| date <= t.end)
+    }
+
+    /// The term after the given one, if any
+    pub fn next(&self, term: &Term) -> Option<&Term> {
+        self.terms.iter().find(|t| t.start > term.end)
+    }
+
+    fn in_break(&self, date: NaiveDate) -> bool {
+        self.breaks.iter().any(|b| b.start <= date && date <= b.end)
+    }
+
+    /// Whether all of the week's weekdays which fall within its term are in
+    /// a break
+    pub fn is_break(&self, week: IsoWeek) -> bool {
+        let Some(term) = self.term(week) else {
+            return false;
+        };
+        let system = system();
+        let mut weekdays = system
+            .dates(week)
+            .filter(|d| !system.is_weekend(d.weekday()))
+            .filter(|d| term.start <= *d && *d <= term.end)
+            .peekable();
+        weekdays.peek().is_some() && weekdays.all(|d| self.in_break(d))
+    }
+
+    /// The teaching week number of the given week, or `None` if it's a break
+    /// or isn't in a term
+    pub fn teaching_week(&self, week: IsoWeek) -> Option<u32> {
+        let term = self.term(week)?;
+        if self.is_break(week) {
+            return None;
+        }
+        let n = term
+            .start
+            .iter_weeks()
+            .map(|d| system().week_of(d))
+            .take_while(|w| *w <= week)
+            .filter(|w| !self.is_break(*w))
+            .count();
+        Some(n as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn week(year: i32, month: u32, day: u32) -> IsoWeek {
+        system().week_of(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    fn error(txt: &str) -> String {
+        match Terms::parse(txt) {
+            Ok(_) => panic!("{txt:?} was accepted"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn teaching_weeks() {
+        let terms = Terms::parse(
+            "term   2025-10-09  2025-12-05  Michaelmas
+             break  2025-11-10  2025-11-14  Reading week
+             term   2026-01-15  2026-03-13  Lent
+             break  2026-02-20  2026-02-24  Long weekend",
+        )
+        .unwrap();
+        let teaching_week = |y, m, d| terms.teaching_week(week(y, m, d));
+        assert_eq!(teaching_week(2025, 10, 1), None);
+        // The term starts on a Thursday
+        assert_eq!(teaching_week(2025, 10, 6), Some(1));
+        assert_eq!(teaching_week(2025, 11, 3), Some(5));
+        // Reading week isn't counted
+        assert!(terms.is_break(week(2025, 11, 10)));
+        assert_eq!(teaching_week(2025, 11, 10), None);
+        assert_eq!(teaching_week(2025, 11, 17), Some(6));
+        assert_eq!(teaching_week(2025, 12, 1), Some(8));
+        assert_eq!(teaching_week(2025, 12, 8), None);
+        assert_eq!(terms.term(week(2026, 1, 12)).unwrap().name, "Lent");
+        assert_eq!(teaching_week(2026, 1, 12), Some(1));
+        // A break which only covers some of the weekdays of each week
+        assert!(!terms.is_break(week(2026, 2, 16)));
+        assert!(!terms.is_break(week(2026, 2, 23)));
+        assert_eq!(teaching_week(2026, 2, 16), Some(6));
+        assert_eq!(teaching_week(2026, 2, 23), Some(7));
+    }
+
+    #[test]
+    fn break_at_the_start() {
+        // The term's only weekdays in its first week are in the break
+        let terms = Terms::parse(
+            "term   2026-01-15  2026-03-13  Lent
+             break  2026-01-15  2026-01-16  Snow",
+        )
+        .unwrap();
+        assert!(terms.is_break(week(2026, 1, 12)));
+        assert_eq!(terms.teaching_week(week(2026, 1, 12)), None);
+        assert_eq!(terms.teaching_week(week(2026, 1, 19)), Some(1));
+    }
+
+    #[test]
+    fn rejected() {
+        assert_eq!(
+            error("term 2025-10-09 2025-12-05 A\nterm 2025-12-05 2026-01-05 B"),
+            "line 2: B overlaps A"
+        );
+        assert_eq!(
+            error("term 2025-10-09 2025-12-05 A\nterm 2025-09-01 2026-01-05 B"),
+            "line 2: B overlaps A"
+        );
+        assert_eq!(
+            error("term 2025-10-09 2025-12-05 A\nbreak 2025-12-01 2025-12-09 B"),
+            "The break B (2025-12-01 to 2025-12-09) isn't within a term"
+        );
+        assert_eq!(
+            error("break 2025-12-01 2025-12-09 B"),
+            "The break B (2025-12-01 to 2025-12-09) isn't within a term"
+        );
+        assert_eq!(
+            error("term 2025-12-05 2025-10-09 A"),
+            "line 1: A ends before it starts"
+        );
+        assert_eq!(
+            error("holiday 2025-10-09 2025-12-05 A"),
+            "line 1: expected \"term\" or \"break\", not holiday"
+        );
+        assert_eq!(error("term 2025-10-09"), "line 1: bad end date");
+    }
+}
@@ -0,0 +1,235 @@
+//! How days are grouped into weeks, and how the weeks are numbered.
+//!
+//! By default, weeks run from Monday to Sunday and are numbered as in ISO
+//! 8601, but weeks can start on any day.  Internally, a week is identified by
+//! the ISO week containing its Monday, but seasons and anything else which
+//! counts weeks within a year go by the numbering of the configured system.
+
+use anyhow::ensure;
+use chrono::{Datelike, Duration, IsoWeek, Local, NaiveDate, Weekday};
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+/// How weeks are numbered within a year
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Numbering {
+    /// Week 1 is the first week with at least four days in the year, as in
+    /// ISO 8601
+    #[default]
+    Iso,
+    /// Week 1 is the week containing 1st January, as in the US and much of
+    /// the Middle East
+    Us,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WeekSystem {
+    pub first_day: Weekday,
+    pub numbering: Numbering,
+    pub weekend: Vec<Weekday>,
+}
+
+impl Default for WeekSystem {
+    fn default() -> WeekSystem {
+        WeekSystem {
+            first_day: Weekday::Mon,
+            numbering: Numbering::Iso,
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+        }
+    }
+}
+
+static SYSTEM: OnceLock<WeekSystem> = OnceLock::new();
+
+/// The week system in use
+pub fn system() -> &'static WeekSystem {
+    SYSTEM.get_or_init(WeekSystem::default)
+}
+
+/// Set the week system, in place of ISO weeks
+pub fn configure(system: WeekSystem) -> anyhow::Result<()> {
+    ensure!(
+        system.weekend.len() < 7,
+        "The weekend can't be the whole week"
+    );
+    ensure!(
+        SYSTEM.set(system).is_ok(),
+        "The week system is already configured"
+    );
+    Ok(())
+}
+
+/// The number of days from `since` until the next `day`, from 0 to 6
+fn days_since(day: Weekday, since: Weekday) -> u32 {
+    (day.num_days_from_monday() + 7 - since.num_days_from_monday()) % 7
+}
+
+/// The Monday of the given ISO week
+pub(crate) fn monday(week: IsoWeek) -> NaiveDate {
+    NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon).unwrap()
+}
+
+impl WeekSystem {
+    /// The days of the week, in order
+    pub fn days(&self) -> [Weekday; 7] {
+        let mut day = self.first_day;
+        [(); 7].map(|()| {
+            let x = day;
+            day = day.succ();
+            x
+        })
+    }
+
+    pub fn is_weekend(&self, day: Weekday) -> bool {
+        self.weekend.contains(&day)
+    }
+
+    /// The week containing the given date
+    pub fn week_of(&self, date: NaiveDate) -> IsoWeek {
+        let start = date - Duration::days(i64::from(days_since(date.weekday(), self.first_day)));
+        let monday = start + Duration::days(i64::from(days_since(Weekday::Mon, self.first_day)));
+        monday.iso_week()
+    }
+
+    /// The current week
+    pub fn this_week(&self) -> IsoWeek {
+        self.week_of(Local::now().date_naive())
+    }
+
+    /// The dates in the given week, in order
+    pub fn dates(&self, week: IsoWeek) -> impl Iterator<Item = NaiveDate> {
+        let start =
+            monday(week) - Duration::days(i64::from(days_since(Weekday::Mon, self.first_day)));
+        start.iter_days().take(7)
+    }
+
+    /// The given day of the given week
+    pub fn date(&self, week: IsoWeek, day: Weekday) -> NaiveDate {
+        self.dates(week).find(|d| d.weekday() == day).unwrap()
+    }
+
+    pub fn prev(&self, week: IsoWeek) -> IsoWeek {
+        (monday(week) - Duration::weeks(1)).iso_week()
+    }
+
+    pub fn succ(&self, week: IsoWeek) -> IsoWeek {
+        (monday(week) + Duration::weeks(1)).iso_week()
+    }
+
+    /// The day which decides which year, and which month, the given week is
+    /// in: the fourth day of the week with ISO numbering, or the last day
+    /// with US numbering
+    pub fn deciding_date(&self, week: IsoWeek) -> NaiveDate {
+        let n = match self.numbering {
+            Numbering::Iso => 3,
+            Numbering::Us => 6,
+        };
+        self.dates(week).nth(n).unwrap()
+    }
+
+    /// The year and number of the given week
+    pub fn number(&self, week: IsoWeek) -> (i32, u8) {
+        let date = self.deciding_date(week);
+        (date.year(), (date.ordinal0() / 7 + 1) as u8)
+    }
+
+    /// The week with the given year and number, if there is one
+    pub fn week(&self, year: i32, number: u32) -> Option<IsoWeek> {
+        let first = self.week_of(match self.numbering {
+            Numbering::Iso => NaiveDate::from_ymd_opt(year, 1, 4)?,
+            Numbering::Us => NaiveDate::from_ymd_opt(year, 1, 1)?,
+        });
+        let date = monday(first).checked_add_signed(Duration::weeks(i64::from(number) - 1))?;
+        let week = date.iso_week();
+        let (y, n) = self.number(week);
+        (y == year && u32::from(n) == number).then_some(week)
+    }
+
+    /// The weeks numbered as part of the given year, if it's in the range of
+    /// dates which can be represented
+    pub fn year(&self, year: i32) -> Option<RangeInclusive<IsoWeek>> {
+        let first = self.week(year, 1)?;
+        let last = self.prev(self.week(year.checked_add(1)?, 1)?);
+        Some(first..=last)
+    }
+
+    /// The column headings for the days of the week, eg. "Mo Tu We Th Fr   Sa
+    /// Su".  There's a gap between weekdays and the weekend.
+    pub fn header(&self) -> String {
+        let mut x = String::new();
+        let mut prev = None;
+        for day in self.days() {
+            if let Some(prev) = prev {
+                x.push_str(if self.is_weekend(prev) != self.is_weekend(day) {
+                    "   "
+                } else {
+                    " "
+                });
+            }
+            x.push_str(&format!("{day}")[..2]);
+            prev = Some(day);
+        }
+        x
+    }
+}
+
+/// The weeks from the start of the range to the end, inclusive
+pub fn weeks_in_range(range: RangeInclusive<IsoWeek>) -> impl Iterator<Item = IsoWeek> {
+    let (start, end) = range.into_inner();
+    std::iter::successors(Some(monday(start)), |d| {
+        d.checked_add_signed(Duration::weeks(1))
+    })
+    .map(|d| d.iso_week())
+    .take_while(move |x| *x <= end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn sunday_first_us_numbering() {
+        let system = WeekSystem {
+            first_day: Weekday::Sun,
+            numbering: Numbering::Us,
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+        };
+        // 1st January 2027 is a Friday
+        let week = system.week_of(ymd(2027, 1, 1));
+        assert_eq!(system.number(week), (2027, 1));
+        assert_eq!(system.week_of(ymd(2026, 12, 27)), week);
+        assert_eq!(system.week_of(ymd(2027, 1, 2)), week);
+        assert_eq!(system.dates(week).next(), Some(ymd(2026, 12, 27)));
+        assert_eq!(system.week(2027, 1), Some(week));
+        assert_eq!(system.number(system.week_of(ymd(2026, 12, 26))), (2026, 52));
+        // 2022 starts on a Saturday and ends on one, so it has 53 weeks
+        let week = system.week(2022, 53).unwrap();
+        assert_eq!(system.dates(week).next(), Some(ymd(2022, 12, 25)));
+        assert_eq!(system.week(2027, 53), None);
+    }
+
+    #[test]
+    fn saturday_first() {
+        let system = WeekSystem {
+            first_day: Weekday::Sat,
+            numbering: Numbering::Iso,
+            weekend: vec![Weekday::Fri, Weekday::Sat],
+        };
+        // The week from Saturday 28th December 2024 has only three days in
+        // 2025, so it's the last week of 2024
+        let week = system.week_of(ymd(2025, 1, 3));
+        assert_eq!(system.dates(week).next(), Some(ymd(2024, 12, 28)));
+        assert_eq!(system.number(week), (2024, 53));
+        let week = system.week_of(ymd(2025, 1, 4));
+        assert_eq!(system.week_of(ymd(2025, 1, 10)), week);
+        assert_eq!(system.number(week), (2025, 1));
+        assert_eq!(system.week(2025, 1), Some(week));
+        assert_eq!(system.week(2025, 53), None);
+        assert_eq!(system.header(), "Sa   Su Mo Tu We Th   Fr");
+    }
+}